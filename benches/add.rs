@@ -0,0 +1,50 @@
+//! Times element-wise addition of contiguous float arrays through the `Add` operator, which uses
+//! the chunked SIMD kernel, against a plain scalar loop over the same data.
+//!
+//! Run with `cargo bench --bench add`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use numru::{Array, Ix, Shape};
+
+const LEN: usize = 1_000_003;
+const ITERATIONS: u32 = 200;
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<T>(name: &str, lhs: &Array<T, Ix<1>>, rhs: &Array<T, Ix<1>>)
+where
+    T: std::ops::Add<Output = T> + Copy,
+{
+    let operator = time(|| {
+        black_box(black_box(lhs) + black_box(rhs));
+    });
+    let scalar = time(|| {
+        let sum: Vec<T> = black_box(lhs.data())
+            .iter()
+            .zip(black_box(rhs.data()))
+            .map(|(&a, &b)| a + b)
+            .collect();
+        black_box(sum);
+    });
+    println!("{name}: operator {operator:?}, scalar loop {scalar:?} per {LEN}-element add");
+}
+
+fn main() {
+    let shape = Shape::new(Ix::<1>::new([LEN]));
+
+    let lhs = Array::from_shape_fn(shape.clone(), |ix| ix[0] as f64 * 0.5);
+    let rhs = Array::from_shape_fn(shape.clone(), |ix| 1.0 / (ix[0] as f64 + 1.0));
+    bench("f64", &lhs, &rhs);
+
+    let lhs = Array::from_shape_fn(shape.clone(), |ix| ix[0] as f32 * 0.5);
+    let rhs = Array::from_shape_fn(shape, |ix| 1.0 / (ix[0] as f32 + 1.0));
+    bench("f32", &lhs, &rhs);
+}
@@ -0,0 +1,26 @@
+use numru::arr;
+
+fn main() {
+    let a = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let b = arr![[0.5, 0.5, 0.5], [2.0, 2.0, 2.0]];
+
+    let sum = &a + &b;
+    println!("a + b =");
+    sum.visualize().execute();
+
+    let difference = &a - &b;
+    println!("a - b =");
+    difference.visualize().execute();
+
+    let product = &a * &b;
+    println!("a * b =");
+    product.visualize().execute();
+
+    let quotient = &a / &b;
+    println!("a / b =");
+    quotient.visualize().decimal_points(2).execute();
+
+    let scaled = &a * 10.0;
+    println!("a * 10 =");
+    scaled.visualize().execute();
+}
@@ -0,0 +1,15 @@
+use numru::full;
+
+fn main() {
+    let a = full!(i64, -1, 3);
+    println!("a.shape() = {:?}", a.shape());
+    a.visualize().execute();
+
+    let b = full!(f64, 0.5, 2, 3);
+    println!("b.shape() = {:?}", b.shape());
+    b.visualize().decimal_points(2).execute();
+
+    let c = full!(i64, 255, 2, 2, 3);
+    println!("c.shape() = {:?}", c.shape());
+    c.visualize().execute();
+}
@@ -0,0 +1,392 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::broadcast::{zip_assign, zip_with, BroadcastShape};
+use crate::simd;
+use crate::{Array, Dimension};
+
+/// Implements an element-wise binary operator for every combination of owned and borrowed arrays.
+/// The operands are broadcast to a common shape, and the result is a newly allocated array.
+/// Panics if the shapes cannot be broadcast together.
+///
+/// An optional fast-path `kernel` is tried first and returns `None` to fall back to the generic
+/// broadcasting loop. It must not need any bounds on `T` beyond the operator's own.
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $op:tt, $name:literal $(, $kernel:path)?) => {
+        impl<T, D, E> $trait<&Array<T, E>> for &Array<T, D>
+        where
+            T: $trait<Output = T> + Clone,
+            D: BroadcastShape<E>,
+            E: Dimension,
+        {
+            type Output = Array<T, D::Output>;
+
+            fn $method(self, rhs: &Array<T, E>) -> Self::Output {
+                $(
+                    if let Some(result) = $kernel(self, rhs) {
+                        return result;
+                    }
+                )?
+                zip_with(self, rhs, |a, b| a.clone() $op b.clone())
+                    .unwrap_or_else(|e| panic!("Cannot {} arrays: {}", $name, e))
+            }
+        }
+
+        impl<T, D, E> $trait<Array<T, E>> for Array<T, D>
+        where
+            T: $trait<Output = T> + Clone,
+            D: BroadcastShape<E>,
+            E: Dimension,
+        {
+            type Output = Array<T, D::Output>;
+
+            fn $method(self, rhs: Array<T, E>) -> Self::Output {
+                &self $op &rhs
+            }
+        }
+
+        impl<T, D, E> $trait<&Array<T, E>> for Array<T, D>
+        where
+            T: $trait<Output = T> + Clone,
+            D: BroadcastShape<E>,
+            E: Dimension,
+        {
+            type Output = Array<T, D::Output>;
+
+            fn $method(self, rhs: &Array<T, E>) -> Self::Output {
+                &self $op rhs
+            }
+        }
+
+        impl<T, D, E> $trait<Array<T, E>> for &Array<T, D>
+        where
+            T: $trait<Output = T> + Clone,
+            D: BroadcastShape<E>,
+            E: Dimension,
+        {
+            type Output = Array<T, D::Output>;
+
+            fn $method(self, rhs: Array<T, E>) -> Self::Output {
+                self $op &rhs
+            }
+        }
+    };
+}
+
+/// Implements an element-wise operator between an owned or borrowed array and a scalar on the
+/// right-hand side, applying the operation to every element.
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, D> $trait<T> for &Array<T, D>
+        where
+            T: $trait<Output = T> + Copy,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: T) -> Array<T, D> {
+                self.map(|&a| a $op rhs)
+            }
+        }
+
+        impl<T, D> $trait<T> for Array<T, D>
+        where
+            T: $trait<Output = T> + Copy,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: T) -> Array<T, D> {
+                &self $op rhs
+            }
+        }
+    };
+}
+
+/// Implements a compound assignment operator that updates an array in place from an owned or
+/// borrowed array, which is broadcast to the shape of the left-hand side. Panics if it cannot be.
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $base:ident, $op:tt, $name:literal) => {
+        impl<T, D, E> $trait<&Array<T, E>> for Array<T, D>
+        where
+            T: $base<Output = T> + Clone,
+            D: Dimension,
+            E: Dimension,
+        {
+            fn $method(&mut self, rhs: &Array<T, E>) {
+                zip_assign(self, rhs, |a, b| *a = a.clone() $op b.clone())
+                    .unwrap_or_else(|e| panic!("Cannot {} arrays in place: {}", $name, e));
+            }
+        }
+
+        impl<T, D, E> $trait<Array<T, E>> for Array<T, D>
+        where
+            T: $base<Output = T> + Clone,
+            D: Dimension,
+            E: Dimension,
+        {
+            fn $method(&mut self, rhs: Array<T, E>) {
+                self.$method(&rhs);
+            }
+        }
+    };
+}
+
+/// Implements a compound assignment operator between an array and a scalar on the right-hand
+/// side, updating every element in place.
+macro_rules! impl_scalar_assign_op {
+    ($trait:ident, $method:ident, $base:ident, $op:tt) => {
+        impl<T, D> $trait<T> for Array<T, D>
+        where
+            T: $base<Output = T> + Copy,
+            D: Dimension,
+        {
+            fn $method(&mut self, rhs: T) {
+                for a in self.as_mut_slice() {
+                    *a = *a $op rhs;
+                }
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, +, "add", simd::add_same_shape);
+impl_binary_op!(Sub, sub, -, "subtract");
+impl_binary_op!(Mul, mul, *, "multiply");
+impl_binary_op!(Div, div, /, "divide");
+
+impl_scalar_op!(Add, add, +);
+impl_scalar_op!(Sub, sub, -);
+impl_scalar_op!(Mul, mul, *);
+impl_scalar_op!(Div, div, /);
+
+impl_assign_op!(AddAssign, add_assign, Add, +, "add");
+impl_assign_op!(SubAssign, sub_assign, Sub, -, "subtract");
+impl_assign_op!(MulAssign, mul_assign, Mul, *, "multiply");
+impl_assign_op!(DivAssign, div_assign, Div, /, "divide");
+
+impl_scalar_assign_op!(AddAssign, add_assign, Add, +);
+impl_scalar_assign_op!(SubAssign, sub_assign, Sub, -);
+impl_scalar_assign_op!(MulAssign, mul_assign, Mul, *);
+impl_scalar_assign_op!(DivAssign, div_assign, Div, /);
+
+impl<T: Neg<Output = T> + Copy, D: Dimension> Neg for &Array<T, D> {
+    type Output = Array<T, D>;
+
+    fn neg(self) -> Array<T, D> {
+        self.map(|&a| -a)
+    }
+}
+
+impl<T: Neg<Output = T> + Copy, D: Dimension> Neg for Array<T, D> {
+    type Output = Array<T, D>;
+
+    fn neg(mut self) -> Array<T, D> {
+        // Negating in place reuses the buffer, and the order of the elements does not matter.
+        for a in self.as_mut_slice() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Order;
+
+    #[test]
+    fn add_i64_1d() {
+        let a = arr![1, 2, 3, 4];
+        let b = arr![10, 20, 30, 40];
+        let c = &a + &b;
+
+        assert_eq!(c.shape().dims(), &[4]);
+        assert_eq!(c.data(), &vec![11, 22, 33, 44]);
+        assert_eq!((a + b).data(), &vec![11, 22, 33, 44]);
+    }
+
+    #[test]
+    fn add_f64_2d() {
+        let a = arr![[1.5, 2.5], [3.5, 4.5], [5.5, 6.5]];
+        let b = arr![[0.5, 0.5], [0.5, 0.5], [0.5, 0.5]];
+        let c = &a + b;
+
+        assert_eq!(c.shape().dims(), &[3, 2]);
+        assert_eq!(c.data(), &vec![2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot add arrays: Incompatible shapes for broadcasting: [2, 2] and [1, 4]"
+    )]
+    fn add_shape_mismatch() {
+        let a = arr![[1, 2], [3, 4]];
+        let b = arr![[1, 2, 3, 4]];
+        let _ = a + &b;
+    }
+
+    #[test]
+    fn sub_i64_1d() {
+        let a = arr![5, 7, 9];
+        let b = arr![1, 2, 3];
+
+        assert_eq!((&a - &b).data(), &vec![4, 5, 6]);
+        assert_eq!((b - a).data(), &vec![-4, -5, -6]);
+    }
+
+    #[test]
+    fn sub_f64_2d() {
+        let a = arr![[1.5, 2.5], [3.5, 4.5]];
+        let b = arr![[0.5, 1.0], [1.5, 2.0]];
+        let c = &a - &b;
+
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data(), &vec![1.0, 1.5, 2.0, 2.5]);
+    }
+
+    #[test]
+    fn mul_i64_1d() {
+        let a = arr![1, 2, 3];
+        let b = arr![4, 5, 6];
+
+        assert_eq!((&a * &b).data(), &vec![4, 10, 18]);
+    }
+
+    #[test]
+    fn mul_f64_2d() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+        let b = arr![[0.5, 0.5], [2.0, 0.25]];
+        let c = a * b;
+
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data(), &vec![0.5, 1.0, 6.0, 1.0]);
+    }
+
+    #[test]
+    fn div_i64_1d() {
+        let a = arr![10, 21, -9];
+        let b = arr![2, 4, 3];
+
+        assert_eq!((&a / &b).data(), &vec![5, 5, -3]);
+    }
+
+    #[test]
+    fn div_f64_2d() {
+        let a = arr![[1.0, 2.0], [-3.0, 0.0]];
+        let b = arr![[4.0, 0.0], [0.0, 0.0]];
+        let c = &a / &b;
+
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data()[0], 0.25);
+        assert_eq!(c.data()[1], f64::INFINITY);
+        assert_eq!(c.data()[2], f64::NEG_INFINITY);
+        assert!(c.data()[3].is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_i64_by_zero() {
+        let _ = arr![1, 2] / arr![1, 0];
+    }
+
+    #[test]
+    fn scalar_mul_i64_1d() {
+        let scaled = arr![1, 2, 3] * 10;
+
+        assert_eq!(scaled.shape().dims(), &[3]);
+        assert_eq!(scaled.data(), &vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn scalar_ops_f64_2d() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+
+        assert_eq!((&a + 1.0).data(), &vec![2.0, 3.0, 4.0, 5.0]);
+        assert_eq!((&a - 0.5).data(), &vec![0.5, 1.5, 2.5, 3.5]);
+        assert_eq!((&a * 2.0).data(), &vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((&a / 4.0).data(), &vec![0.25, 0.5, 0.75, 1.0]);
+        assert_eq!((&a * 2.0).shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn scalar_and_array_ops_mix() {
+        let a = arr![1, 2, 3];
+        let b = arr![1, 1, 1];
+
+        assert_eq!((&(&a * 2) + &b).data(), &vec![3, 5, 7]);
+    }
+
+    #[test]
+    fn assign_ops_i64_1d() {
+        let mut a = arr![1, 2, 3];
+        let b = arr![10, 20, 30];
+
+        a += &b;
+        assert_eq!(a.data(), &vec![11, 22, 33]);
+        a -= arr![1, 2, 3];
+        assert_eq!(a.data(), &vec![10, 20, 30]);
+        a *= &b;
+        assert_eq!(a.data(), &vec![100, 400, 900]);
+        a /= &b;
+        assert_eq!(a.data(), &vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn assign_ops_broadcast_and_col_major() {
+        let mut a = arr![[1.0, 2.0], [3.0, 4.0]].to_order(Order::ColMajor);
+        a += &arr![10.0, 20.0];
+        assert_eq!(a, arr![[11.0, 22.0], [13.0, 24.0]]);
+
+        a *= &arr![[1.0, 2.0], [3.0, 4.0]];
+        assert_eq!(a, arr![[11.0, 44.0], [39.0, 96.0]]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot add arrays in place: Incompatible shapes for broadcasting: [2] and [2, 2]"
+    )]
+    fn assign_op_cannot_grow_lhs() {
+        let mut a = arr![1, 2];
+        a += &arr![[1, 2], [3, 4]];
+    }
+
+    #[test]
+    fn scalar_assign_ops() {
+        let mut a = arr![[1, 2], [3, 4]];
+        a *= 2;
+        assert_eq!(a.data(), &vec![2, 4, 6, 8]);
+        a += 1;
+        a -= 3;
+        assert_eq!(a.data(), &vec![0, 2, 4, 6]);
+
+        let mut b = arr![1.0, 3.0];
+        b /= 2.0;
+        assert_eq!(b.data(), &vec![0.5, 1.5]);
+    }
+
+    #[test]
+    fn neg_owned_and_borrowed() {
+        let a = arr![1, -2, 3];
+        assert_eq!((-&a).data(), &vec![-1, 2, -3]);
+        assert_eq!((-a).data(), &vec![-1, 2, -3]);
+
+        let b = arr![[0.5, -1.5], [2.0, -0.0]].to_order(Order::ColMajor);
+        assert_eq!(-&b, arr![[-0.5, 1.5], [-2.0, 0.0]]);
+        assert_eq!((-b).order(), Order::ColMajor);
+    }
+
+    #[test]
+    fn add_needs_no_static_bound() {
+        fn sum<T: std::ops::Add<Output = T> + Clone>(
+            a: &crate::Array<T, crate::Ix<1>>,
+            b: &crate::Array<T, crate::Ix<1>>,
+        ) -> crate::Array<T, crate::Ix<1>> {
+            a + b
+        }
+
+        assert_eq!(
+            sum(&arr![1.0, 2.0], &arr![0.5, 0.25]).data(),
+            &vec![1.5, 2.25]
+        );
+        assert_eq!(sum(&arr![1, 2], &arr![3, 4]).data(), &vec![4, 6]);
+    }
+}
@@ -1,16 +1,25 @@
-use num_traits::{One, Zero};
+use num_traits::{AsPrimitive, Num, NumCast, One, Zero};
 
-use crate::ArrayError;
-use crate::{Dimension, Shape};
-use std::fmt::Debug;
+use crate::comparison::pick;
+use crate::shape::{strided_offset, strided_offset_unchecked, Order};
+use crate::view::Iter;
+use crate::{ArrayError, ShapeError};
+use crate::{Dimension, Ix, IxDyn, RemoveAxis, Shape};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::ops::{Index, IndexMut};
 
 /// Represents a multi-dimensional array with elements of type `T` and dimension `D`.
-#[derive(Debug)]
 pub struct Array<T, D: Dimension> {
     data: Vec<T>,
     shape: Shape<D>,
+    strides: D,
+    order: Order,
 }
 
+/// An array whose number of dimensions is only known at runtime.
+pub type ArrayDyn<T> = Array<T, IxDyn>;
+
 impl<T, D: Dimension> Array<T, D> {
     /// Constructs a new `Array` from a vector of data and a shape.
     pub fn new(data: Vec<T>, shape: Shape<D>) -> Result<Self, ArrayError> {
@@ -21,21 +30,432 @@ impl<T, D: Dimension> Array<T, D> {
                 actual: data.len(),
             });
         }
-        Ok(Array { data, shape })
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Constructs a new `Array` from a vector of data laid out in the given memory `order` and a
+    /// shape. For `Order::ColMajor`, `data` lists the elements with the first index varying fastest.
+    pub fn new_with_order(data: Vec<T>, shape: Shape<D>, order: Order) -> Result<Self, ArrayError> {
+        Array::new(data, shape)
+            .map(|array| Array::from_parts_in_order(array.data, array.shape, order))
+    }
+
+    /// Constructs an array of the given shape by calling `f` with the index of each element in
+    /// row-major order.
+    pub fn from_shape_fn<F: FnMut(D) -> T>(shape: Shape<D>, mut f: F) -> Self {
+        let data = (0..shape.size()).map(|i| f(shape.unravel(i))).collect();
+        Array::from_parts(data, shape)
+    }
+
+    /// Constructs an array of the given shape from the elements of `iter` in row-major order.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if `iter` does not yield exactly as many elements
+    /// as the shape holds.
+    pub fn from_iter_shape<I: IntoIterator<Item = T>>(
+        iter: I,
+        shape: Shape<D>,
+    ) -> Result<Self, ShapeError> {
+        let data: Vec<T> = iter.into_iter().collect();
+        if data.len() != shape.size() {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "iterator yielded {} elements, but shape {:?} holds {}",
+                data.len(),
+                shape.dims(),
+                shape.size()
+            )));
+        }
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Checks that `axis` is a valid axis for this array.
+    pub(crate) fn check_axis(&self, axis: usize) -> Result<(), ArrayError> {
+        let ndim = self.shape.raw_dim().ndim();
+        if axis >= ndim {
+            return Err(ArrayError::InvalidAxis(format!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis, ndim
+            )));
+        }
+        Ok(())
+    }
+
+    /// Folds every lane along `axis` into a single value, producing an array with that axis removed.
+    pub(crate) fn fold_axis<U, F>(
+        &self,
+        axis: usize,
+        init: U,
+        mut f: F,
+    ) -> Result<Array<U, D::Smaller>, ArrayError>
+    where
+        D: RemoveAxis,
+        U: Clone,
+        F: FnMut(U, &T) -> U,
+    {
+        self.check_axis(axis)?;
+        let (outer, len, inner) = self.shape.axis_blocks(axis);
+
+        let mut data = Vec::with_capacity(outer * inner);
+        for o in 0..outer {
+            for k in 0..inner {
+                let start = o * len * inner + k;
+                let lane = (0..len).map(|i| &self.data[self.row_major_offset(start + i * inner)]);
+                data.push(lane.fold(init.clone(), &mut f));
+            }
+        }
+
+        let shape = Shape::new(self.shape.raw_dim().remove_axis(axis));
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Replaces every lane along `axis` with its running accumulation under `f`, producing an
+    /// array of the same shape whose first element in each lane is unchanged.
+    pub(crate) fn accumulate_axis<F>(&self, axis: usize, mut f: F) -> Result<Self, ArrayError>
+    where
+        T: Copy,
+        F: FnMut(T, T) -> T,
+    {
+        self.check_axis(axis)?;
+        let (outer, len, inner) = self.shape.axis_blocks(axis);
+
+        let mut data = self.row_major_data().into_owned();
+        for o in 0..outer {
+            for i in 1..len {
+                let start = o * len * inner + i * inner;
+                for k in start..start + inner {
+                    data[k] = f(data[k - inner], data[k]);
+                }
+            }
+        }
+
+        Ok(Array::from_parts(data, self.shape.clone()))
+    }
+
+    /// Builds an array from data and a shape that are already known to agree in size.
+    pub(crate) fn from_parts(data: Vec<T>, shape: Shape<D>) -> Self {
+        Array::from_parts_in_order(data, shape, Order::RowMajor)
+    }
+
+    /// Builds an array from data laid out in `order` and a shape, already known to agree in size.
+    pub(crate) fn from_parts_in_order(data: Vec<T>, shape: Shape<D>, order: Order) -> Self {
+        debug_assert_eq!(data.len(), shape.size());
+        let strides = shape.strides_for(order);
+        Array {
+            data,
+            shape,
+            strides,
+            order,
+        }
+    }
+
+    /// Returns the elements in row-major order, borrowing the buffer when the array is already
+    /// row-major and copying the elements into that order otherwise. Operations that index the
+    /// data by row-major arithmetic read it through this.
+    pub(crate) fn row_major_data(&self) -> Cow<'_, [T]>
+    where
+        T: Clone,
+    {
+        match self.order {
+            Order::RowMajor => Cow::Borrowed(&self.data),
+            Order::ColMajor => Cow::Owned(self.iter().cloned().collect()),
+        }
+    }
+
+    /// Returns the offset into the buffer of the element at position `i` in row-major order.
+    pub(crate) fn row_major_offset(&self, i: usize) -> usize {
+        match self.order {
+            Order::RowMajor => i,
+            Order::ColMajor => {
+                strided_offset_unchecked(self.strides.dims(), self.shape.unravel(i).dims())
+            }
+        }
     }
 
-    /// Returns a reference to the underlying data vector.
+    /// Returns a reference to the underlying data vector, in the memory order of the array.
+    /// Use `iter` to visit the elements in row-major order whatever the memory order.
     pub fn data(&self) -> &Vec<T> {
         &self.data
     }
 
+    /// Consumes the array and returns its flat data in the memory order of the array.
+    pub fn into_raw_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns the elements as a slice in the memory order of the array. An owned array always
+    /// stores its elements contiguously; use `ArrayView::as_slice` for views, which may not be
+    /// contiguous.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the elements as a mutable slice in the memory order of the array.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the memory order of the elements of the array.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Consumes the array and returns one with the same elements laid out in `order`, moving the
+    /// elements rather than cloning them. The data is unchanged if it is already in `order`.
+    pub fn into_order(self, order: Order) -> Self {
+        if order == self.order {
+            return self;
+        }
+
+        let mut source: Vec<Option<T>> = self.data.into_iter().map(Some).collect();
+        let mut data: Vec<Option<T>> = (0..source.len()).map(|_| None).collect();
+        let target = self.shape.strides_for(order);
+        for i in 0..source.len() {
+            let index = self.shape.unravel(i);
+            let from = strided_offset_unchecked(self.strides.dims(), index.dims());
+            data[strided_offset_unchecked(target.dims(), index.dims())] = source[from].take();
+        }
+
+        let data = data.into_iter().collect::<Option<_>>();
+        Array::from_parts_in_order(
+            data.expect("every element is moved once"),
+            self.shape,
+            order,
+        )
+    }
+
+    /// Returns a copy of the array with its elements laid out in `order`.
+    pub fn to_order(&self, order: Order) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = self.data.clone();
+        let target = self.shape.strides_for(order);
+        for i in 0..data.len() {
+            let index = self.shape.unravel(i);
+            let from = strided_offset_unchecked(self.strides.dims(), index.dims());
+            data[strided_offset_unchecked(target.dims(), index.dims())] = self.data[from].clone();
+        }
+        Array::from_parts_in_order(data, self.shape.clone(), order)
+    }
+
     /// Returns a reference to the shape of the array.
     pub fn shape(&self) -> &Shape<D> {
         &self.shape
     }
+
+    /// Returns the strides of the array: the number of elements to step over in the flat data to
+    /// move by one along each axis. For an owned array these are the strides of its shape in its
+    /// memory order.
+    pub fn strides(&self) -> &[usize] {
+        self.strides.dims()
+    }
+
+    /// Returns the strides of the array as a dimension of the same rank.
+    pub(crate) fn raw_strides(&self) -> &D {
+        &self.strides
+    }
+
+    /// Returns the number of dimensions (the rank) of the array.
+    pub fn ndim(&self) -> usize {
+        self.shape.dims().len()
+    }
+
+    /// Returns the total number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the array has no elements, that is, if any of its axes has length zero.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns a reference to the element at the given coordinate, or `None` if any component
+    /// of the coordinate is out of bounds.
+    pub fn get(&self, index: D) -> Option<&T> {
+        let offset = strided_offset(self.shape.dims(), self.strides.dims(), index.dims())?;
+        self.data.get(offset)
+    }
+
+    /// Returns a mutable reference to the element at the given coordinate, or `None` if any
+    /// component of the coordinate is out of bounds.
+    pub fn get_mut(&mut self, index: D) -> Option<&mut T> {
+        let offset = strided_offset(self.shape.dims(), self.strides.dims(), index.dims())?;
+        self.data.get_mut(offset)
+    }
+
+    /// Returns an iterator over the elements of the array in row-major order, whatever the
+    /// memory order of the array.
+    pub fn iter(&self) -> Iter<'_, T, D> {
+        self.view().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the array in row-major
+    /// order, for transforming the array in place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let order = self.order;
+        let mut offsets = Vec::new();
+        if order == Order::ColMajor {
+            offsets = (0..self.data.len())
+                .map(|i| {
+                    strided_offset_unchecked(self.strides.dims(), self.shape.unravel(i).dims())
+                })
+                .collect();
+        }
+
+        // Column-major elements are handed out in logical order from a table of their slots.
+        let mut elements = self.data.iter_mut();
+        let mut slots: Vec<Option<&mut T>> = Vec::new();
+        if order == Order::ColMajor {
+            slots = elements.by_ref().map(Some).collect();
+        }
+        let mut offsets = offsets.into_iter();
+        std::iter::from_fn(move || match order {
+            Order::RowMajor => elements.next(),
+            Order::ColMajor => offsets.next().and_then(|offset| slots[offset].take()),
+        })
+    }
+
+    /// Returns an iterator over the elements of the array in row-major order, each paired with
+    /// its coordinate.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (D, &T)> {
+        self.iter()
+            .enumerate()
+            .map(move |(i, x)| (self.shape.unravel(i), x))
+    }
+
+    /// Applies `f` to every element, returning a new array of the same shape and memory order
+    /// whose element type may differ. The original array is left unchanged.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Array<U, D> {
+        // The result uses the same layout, so mapping the buffer directly keeps every element in
+        // its place.
+        let data = self.data.iter().map(f).collect();
+        Array::from_parts_in_order(data, self.shape.clone(), self.order)
+    }
+
+    /// Converts every element to `U` with a lossless `Into` conversion, preserving the shape.
+    pub fn cast<U>(&self) -> Array<U, D>
+    where
+        T: Into<U> + Copy,
+    {
+        self.map(|&x| x.into())
+    }
+
+    /// Converts every element to `U` like an `as` cast, preserving the shape. Conversions may
+    /// lose information: floats are truncated towards zero and saturate at the bounds of an
+    /// integer type, and integers wrap when narrowed.
+    pub fn cast_lossy<U>(&self) -> Array<U, D>
+    where
+        T: AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|&x| x.as_())
+    }
+
+    /// Overwrites every element with `value`, keeping the shape.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Applies `f` to a mutable reference to every element in place, without allocating.
+    pub fn map_inplace<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Replaces every element in place with the result of applying `f` to its value.
+    pub fn mapv_inplace<F: FnMut(T) -> T>(&mut self, mut f: F)
+    where
+        T: Copy,
+    {
+        self.data.iter_mut().for_each(|x| *x = f(*x));
+    }
+
+    /// Converts the array into one with a dynamic number of dimensions, keeping the data and
+    /// shape unchanged.
+    pub fn into_dyn(self) -> ArrayDyn<T> {
+        let shape = Shape::new(IxDyn::new(self.shape.dims()));
+        Array::from_parts_in_order(self.data, shape, self.order)
+    }
+
+    /// Converts the array to the dimension type `E`, keeping the data and shape unchanged.
+    /// This is typically used to turn an `ArrayDyn` into an array of a fixed rank once the rank
+    /// is known.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the number of dimensions does not match the
+    /// rank of `E`.
+    pub fn into_dimensionality<E: Dimension>(self) -> Result<Array<T, E>, ShapeError> {
+        match E::from_dims(self.shape.dims()) {
+            Some(raw_dim) => Ok(Array::from_parts_in_order(
+                self.data,
+                Shape::new(raw_dim),
+                self.order,
+            )),
+            None => Err(ShapeError::IncompatibleShape(format!(
+                "cannot convert array of shape {:?} to a different number of dimensions",
+                self.shape.dims()
+            ))),
+        }
+    }
+
+    /// Consumes the array and returns it with a new shape, keeping the elements in row-major
+    /// order. The result is row-major, so a column-major array is reordered first.
+    ///
+    /// The product of the new dimensions must equal the current number of elements.
+    pub fn reshape<E: Dimension>(self, shape: E) -> Result<Array<T, E>, ShapeError> {
+        if shape.size() != self.data.len() {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot reshape array of {} elements with shape {:?} into shape {:?} ({} elements)",
+                self.data.len(),
+                self.shape.dims(),
+                shape.dims(),
+                shape.size()
+            )));
+        }
+        let data = self.into_order(Order::RowMajor).data;
+        Ok(Array::from_parts(data, Shape::new(shape)))
+    }
+
+    /// Returns a new 1D array containing all elements in row-major order, leaving this array untouched.
+    pub fn flatten(&self) -> Array<T, Ix<1>>
+    where
+        T: Clone,
+    {
+        Array::from_parts(
+            self.iter().cloned().collect(),
+            Shape::new(Ix::<1>::new([self.data.len()])),
+        )
+    }
+
+    /// Consumes the array and returns its elements as a 1D array in row-major order without copying.
+    pub fn ravel(self) -> Array<T, Ix<1>> {
+        let len = self.data.len();
+        let data = self.into_order(Order::RowMajor).data;
+        Array::from_parts(data, Shape::new(Ix::<1>::new([len])))
+    }
+}
+
+impl<T: Clone, D: Dimension> Array<T, D> {
+    /// Constructs an array of the given shape with every element set to `value`.
+    pub fn full(shape: Shape<D>, value: T) -> Self {
+        Array::from_parts(vec![value; shape.size()], shape)
+    }
 }
 
 impl<T: Zero + One + Copy, D: Dimension> Array<T, D> {
+    /// Constructs an array of the given shape filled with zeros. The in-place `zeros` method
+    /// instead overwrites the elements of an existing array.
+    pub fn new_zeros(shape: Shape<D>) -> Self {
+        Array::full(shape, T::zero())
+    }
+
+    /// Constructs an array of the given shape filled with ones. The in-place `ones` method
+    /// instead overwrites the elements of an existing array.
+    pub fn new_ones(shape: Shape<D>) -> Self {
+        Array::full(shape, T::one())
+    }
+
     /// Replaces all elements in the array with zeros using num_traits::Zero.
     /// The shape and dimension of the array are preserved.
     pub fn zeros(&mut self) {
@@ -51,6 +471,104 @@ impl<T: Zero + One + Copy, D: Dimension> Array<T, D> {
     }
 }
 
+impl<T: Zero + One + Copy> Array<T, Ix<2>> {
+    /// Constructs an `n x n` identity matrix with ones on the main diagonal and zeros elsewhere.
+    /// An `n` of zero yields an empty `0 x 0` array.
+    pub fn eye(n: usize) -> Self {
+        let mut data = vec![T::zero(); n * n];
+        for i in 0..n {
+            data[i * n + i] = T::one();
+        }
+        Array::from_parts(data, Shape::new(Ix::<2>::new([n, n])))
+    }
+}
+
+impl<T: Clone> Array<T, Ix<2>> {
+    /// Returns the transpose of the matrix, turning an `m x n` array into an `n x m` array.
+    /// The data is physically reordered so the result stays contiguous in row-major order.
+    pub fn transpose(&self) -> Self {
+        let rows = self.shape.dims()[0];
+        let cols = self.shape.dims()[1];
+
+        let source: &[T] = &self.row_major_data();
+        let data = (0..cols)
+            .flat_map(|col| (0..rows).map(move |row| source[row * cols + col].clone()))
+            .collect();
+
+        Array::from_parts(data, Shape::new(Ix::<2>::new([cols, rows])))
+    }
+}
+
+impl<T: Num + NumCast + PartialOrd + Copy> Array<T, Ix<1>> {
+    /// Constructs a 1D array of evenly spaced values `start, start + step, ...` up to but
+    /// excluding `stop`. The array has `ceil((stop - start) / step)` elements, and is empty
+    /// when the sign of `step` does not match the direction from `start` to `stop`.
+    ///
+    /// Returns `ArrayError::InvalidArgument` if `step` is zero or that element count is not
+    /// finite or does not fit in `usize`.
+    pub fn arange(start: T, stop: T, step: T) -> Result<Self, ArrayError> {
+        if step == T::zero() {
+            return Err(ArrayError::InvalidArgument(
+                "arange step must be non-zero".to_string(),
+            ));
+        }
+
+        let to_f64 = |value: T| {
+            value.to_f64().ok_or_else(|| {
+                ArrayError::InvalidArgument(
+                    "arange bounds must be representable as f64".to_string(),
+                )
+            })
+        };
+        let steps = (to_f64(stop)? - to_f64(start)?) / to_f64(step)?;
+        if !steps.is_finite() || steps >= usize::MAX as f64 {
+            return Err(ArrayError::InvalidArgument(format!(
+                "arange length must be finite and fit in usize, got {}",
+                steps
+            )));
+        }
+        let len = if steps > 0.0 {
+            steps.ceil() as usize
+        } else {
+            0
+        };
+
+        let data = (0..len)
+            .map(|i| {
+                T::from(i).map(|i| start + i * step).ok_or_else(|| {
+                    ArrayError::InvalidArgument(format!(
+                        "arange index {} is not representable in the element type",
+                        i
+                    ))
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        Ok(Array::from_parts(data, Shape::new(Ix::<1>::new([len]))))
+    }
+}
+
+impl Array<f64, Ix<1>> {
+    /// Constructs a 1D array of `num` evenly spaced values over the closed interval `[start, stop]`.
+    /// Each point is computed as `start + i * step`, and the last element is set to exactly `stop`.
+    /// A `num` of one yields `[start]` and a `num` of zero yields an empty array.
+    pub fn linspace(start: f64, stop: f64, num: usize) -> Self {
+        let mut data: Vec<f64> = match num {
+            0 => Vec::new(),
+            1 => vec![start],
+            _ => {
+                let step = (stop - start) / (num - 1) as f64;
+                (0..num).map(|i| start + i as f64 * step).collect()
+            }
+        };
+        if num > 1 {
+            data[num - 1] = stop;
+        }
+
+        Array::from_parts(data, Shape::new(Ix::<1>::new([num])))
+    }
+}
+
 impl<D: Dimension> Array<i64, D> {
     /// Returns the data type string for an array of `i64`.
     pub fn dtype(&self) -> &'static str {
@@ -74,6 +592,7 @@ where
         if self.data.is_empty() {
             return Err(ArrayError::EmptyArray);
         }
+        let data: &[T] = &self.row_major_data();
 
         let raw_dim = self.shape.raw_dim();
         let ndim = raw_dim.ndim();
@@ -88,8 +607,7 @@ where
         }
 
         match ndim {
-            1 => Ok(vec![*self
-                .data
+            1 => Ok(vec![*data
                 .iter()
                 .max_by(|a, b| a.partial_cmp(b).unwrap())
                 .ok_or(ArrayError::EmptyArray)?]),
@@ -102,7 +620,7 @@ where
                         (0..cols)
                             .map(|col| {
                                 (0..rows)
-                                    .map(|row| self.data[row * cols + col])
+                                    .map(|row| data[row * cols + col])
                                     .max_by(|a, b| a.partial_cmp(b).unwrap())
                                     .ok_or(ArrayError::EmptyArray)
                             })
@@ -110,17 +628,16 @@ where
                     } else {
                         (0..rows)
                             .map(|row| {
-                                self.data[row * cols..(row + 1) * cols]
+                                data[row * cols..(row + 1) * cols]
                                     .iter()
                                     .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .map(|&v| v)
+                                    .copied()
                                     .ok_or(ArrayError::EmptyArray)
                             })
                             .collect::<Result<Vec<T>, _>>()
                     }
                 } else {
-                    Ok(vec![*self
-                        .data
+                    Ok(vec![*data
                         .iter()
                         .max_by(|a, b| a.partial_cmp(b).unwrap())
                         .ok_or(ArrayError::EmptyArray)?])
@@ -136,7 +653,7 @@ where
                         0 => (0..rows * cols)
                             .map(|i| {
                                 (0..depth)
-                                    .map(|d| self.data[d * rows * cols + i])
+                                    .map(|d| data[d * rows * cols + i])
                                     .max_by(|a, b| a.partial_cmp(b).unwrap())
                                     .ok_or(ArrayError::EmptyArray)
                             })
@@ -145,7 +662,7 @@ where
                             .flat_map(|d| {
                                 (0..cols).map(move |c| {
                                     (0..rows)
-                                        .map(|r| self.data[d * rows * cols + r * cols + c])
+                                        .map(|r| data[d * rows * cols + r * cols + c])
                                         .max_by(|a, b| a.partial_cmp(b).unwrap())
                                         .ok_or(ArrayError::EmptyArray)
                                 })
@@ -155,10 +672,10 @@ where
                             .flat_map(|d| {
                                 (0..rows).map(move |r| {
                                     let row_start = d * rows * cols + r * cols;
-                                    self.data[row_start..row_start + cols]
+                                    data[row_start..row_start + cols]
                                         .iter()
                                         .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .map(|&v| v)
+                                        .copied()
                                         .ok_or(ArrayError::EmptyArray)
                                 })
                             })
@@ -166,8 +683,7 @@ where
                         _ => unreachable!(),
                     }
                 } else {
-                    Ok(vec![*self
-                        .data
+                    Ok(vec![*data
                         .iter()
                         .max_by(|a, b| a.partial_cmp(b).unwrap())
                         .ok_or(ArrayError::EmptyArray)?])
@@ -185,6 +701,7 @@ where
         if self.data.is_empty() {
             return Err(ArrayError::EmptyArray);
         }
+        let data: &[T] = &self.row_major_data();
 
         let raw_dim = self.shape.raw_dim();
         let ndim = raw_dim.ndim();
@@ -199,8 +716,7 @@ where
         }
 
         match ndim {
-            1 => Ok(vec![*self
-                .data
+            1 => Ok(vec![*data
                 .iter()
                 .min_by(|a, b| a.partial_cmp(b).unwrap())
                 .ok_or(ArrayError::EmptyArray)?]),
@@ -213,7 +729,7 @@ where
                         (0..cols)
                             .map(|col| {
                                 (0..rows)
-                                    .map(|row| self.data[row * cols + col])
+                                    .map(|row| data[row * cols + col])
                                     .min_by(|a, b| a.partial_cmp(b).unwrap())
                                     .ok_or(ArrayError::EmptyArray)
                             })
@@ -221,17 +737,16 @@ where
                     } else {
                         (0..rows)
                             .map(|row| {
-                                self.data[row * cols..(row + 1) * cols]
+                                data[row * cols..(row + 1) * cols]
                                     .iter()
                                     .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .map(|&v| v)
+                                    .copied()
                                     .ok_or(ArrayError::EmptyArray)
                             })
                             .collect::<Result<Vec<T>, _>>()
                     }
                 } else {
-                    Ok(vec![*self
-                        .data
+                    Ok(vec![*data
                         .iter()
                         .min_by(|a, b| a.partial_cmp(b).unwrap())
                         .ok_or(ArrayError::EmptyArray)?])
@@ -247,7 +762,7 @@ where
                         0 => (0..rows * cols)
                             .map(|i| {
                                 (0..depth)
-                                    .map(|d| self.data[d * rows * cols + i])
+                                    .map(|d| data[d * rows * cols + i])
                                     .min_by(|a, b| a.partial_cmp(b).unwrap())
                                     .ok_or(ArrayError::EmptyArray)
                             })
@@ -256,7 +771,7 @@ where
                             .flat_map(|d| {
                                 (0..cols).map(move |c| {
                                     (0..rows)
-                                        .map(|r| self.data[d * rows * cols + r * cols + c])
+                                        .map(|r| data[d * rows * cols + r * cols + c])
                                         .min_by(|a, b| a.partial_cmp(b).unwrap())
                                         .ok_or(ArrayError::EmptyArray)
                                 })
@@ -266,10 +781,10 @@ where
                             .flat_map(|d| {
                                 (0..rows).map(move |r| {
                                     let row_start = d * rows * cols + r * cols;
-                                    self.data[row_start..row_start + cols]
+                                    data[row_start..row_start + cols]
                                         .iter()
                                         .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .map(|&v| v)
+                                        .copied()
                                         .ok_or(ArrayError::EmptyArray)
                                 })
                             })
@@ -277,8 +792,7 @@ where
                         _ => unreachable!(),
                     }
                 } else {
-                    Ok(vec![*self
-                        .data
+                    Ok(vec![*data
                         .iter()
                         .min_by(|a, b| a.partial_cmp(b).unwrap())
                         .ok_or(ArrayError::EmptyArray)?])
@@ -299,6 +813,7 @@ where
         if self.data.is_empty() {
             return Err(ArrayError::EmptyArray);
         }
+        let data: &[T] = &self.row_major_data();
 
         let raw_dim = self.shape.raw_dim();
         let ndim = raw_dim.ndim();
@@ -314,8 +829,8 @@ where
 
         match ndim {
             1 => {
-                let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
-                Ok(vec![sum / self.data.len() as f64])
+                let sum: f64 = data.iter().map(|&x| Into::<f64>::into(x)).sum();
+                Ok(vec![sum / data.len() as f64])
             }
             2 => {
                 let rows = raw_dim.dims()[0];
@@ -326,7 +841,7 @@ where
                         (0..cols)
                             .map(|col| {
                                 let sum: f64 = (0..rows)
-                                    .map(|row| Into::<f64>::into(self.data[row * cols + col]))
+                                    .map(|row| Into::<f64>::into(data[row * cols + col]))
                                     .sum();
                                 Ok(sum / rows as f64)
                             })
@@ -334,7 +849,7 @@ where
                     } else {
                         (0..rows)
                             .map(|row| {
-                                let sum: f64 = self.data[row * cols..(row + 1) * cols]
+                                let sum: f64 = data[row * cols..(row + 1) * cols]
                                     .iter()
                                     .map(|&x| Into::<f64>::into(x))
                                     .sum();
@@ -343,7 +858,7 @@ where
                             .collect()
                     }
                 } else {
-                    let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
+                    let sum: f64 = data.iter().map(|&x| Into::<f64>::into(x)).sum();
                     Ok(vec![sum / (rows * cols) as f64])
                 }
             }
@@ -357,7 +872,7 @@ where
                         0 => (0..rows * cols)
                             .map(|i| {
                                 let sum: f64 = (0..depth)
-                                    .map(|d| Into::<f64>::into(self.data[d * rows * cols + i]))
+                                    .map(|d| Into::<f64>::into(data[d * rows * cols + i]))
                                     .sum();
                                 Ok(sum / depth as f64)
                             })
@@ -366,7 +881,9 @@ where
                             .flat_map(|d| {
                                 (0..cols).map(move |c| {
                                     let sum: f64 = (0..rows)
-                                        .map(|r| Into::<f64>::into(self.data[d * rows * cols + r * cols + c]))
+                                        .map(|r| {
+                                            Into::<f64>::into(data[d * rows * cols + r * cols + c])
+                                        })
                                         .sum();
                                     Ok(sum / rows as f64)
                                 })
@@ -376,7 +893,7 @@ where
                             .flat_map(|d| {
                                 (0..rows).map(move |r| {
                                     let row_start = d * rows * cols + r * cols;
-                                    let sum: f64 = self.data[row_start..row_start + cols]
+                                    let sum: f64 = data[row_start..row_start + cols]
                                         .iter()
                                         .map(|&x| Into::<f64>::into(x))
                                         .sum();
@@ -387,7 +904,7 @@ where
                         _ => unreachable!(),
                     }
                 } else {
-                    let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
+                    let sum: f64 = data.iter().map(|&x| Into::<f64>::into(x)).sum();
                     Ok(vec![sum / (depth * rows * cols) as f64])
                 }
             }
@@ -399,11 +916,320 @@ where
     }
 }
 
+impl<T: Zero + Copy, D: Dimension> Array<T, D> {
+    /// Returns the sum of all elements in the array, regardless of its dimensionality.
+    /// An empty array sums to zero.
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Sums the elements along the given axis, returning an array with that axis removed.
+    pub fn sum_axis(&self, axis: usize) -> Result<Array<T, D::Smaller>, ArrayError>
+    where
+        D: RemoveAxis,
+    {
+        self.fold_axis(axis, T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Computes the cumulative sum along the given axis, returning an array of the same shape.
+    pub fn cumsum(&self, axis: usize) -> Result<Self, ArrayError> {
+        self.accumulate_axis(axis, |acc, x| acc + x)
+    }
+}
+
+impl<T: Zero, D: Dimension> Array<T, D> {
+    /// Counts the elements that are not zero. For floats, both `0.0` and `-0.0` count as zero,
+    /// while `NaN` does not.
+    pub fn count_nonzero(&self) -> usize {
+        self.data.iter().filter(|x| !x.is_zero()).count()
+    }
+
+    /// Counts the non-zero elements along the given axis, returning an array with that axis
+    /// removed.
+    pub fn count_nonzero_axis(&self, axis: usize) -> Result<Array<usize, D::Smaller>, ArrayError>
+    where
+        D: RemoveAxis,
+    {
+        self.fold_axis(
+            axis,
+            0,
+            |count, x| if x.is_zero() { count } else { count + 1 },
+        )
+    }
+}
+
+impl<T: One + Copy, D: Dimension> Array<T, D> {
+    /// Returns the product of all elements in the array, regardless of its dimensionality.
+    /// An empty array multiplies to one.
+    pub fn prod(&self) -> T {
+        self.data.iter().fold(T::one(), |acc, &x| acc * x)
+    }
+
+    /// Multiplies the elements along the given axis, returning an array with that axis removed.
+    pub fn prod_axis(&self, axis: usize) -> Result<Array<T, D::Smaller>, ArrayError>
+    where
+        D: RemoveAxis,
+    {
+        self.fold_axis(axis, T::one(), |acc, &x| acc * x)
+    }
+
+    /// Computes the cumulative product along the given axis, returning an array of the same shape.
+    pub fn cumprod(&self, axis: usize) -> Result<Self, ArrayError> {
+        self.accumulate_axis(axis, |acc, x| acc * x)
+    }
+}
+
+impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
+    /// Computes the running maximum along the given axis, returning an array of the same shape.
+    /// As with `maximum`, a `NaN` carries on to every later element of its lane.
+    pub fn cummax(&self, axis: usize) -> Result<Self, ArrayError> {
+        self.accumulate_axis(axis, |acc, x| pick(acc, x, Ordering::Greater))
+    }
+
+    /// Computes the running minimum along the given axis, returning an array of the same shape.
+    /// As with `minimum`, a `NaN` carries on to every later element of its lane.
+    pub fn cummin(&self, axis: usize) -> Result<Self, ArrayError> {
+        self.accumulate_axis(axis, |acc, x| pick(acc, x, Ordering::Less))
+    }
+}
+
+impl<T: Into<f64> + Copy, D: Dimension> Array<T, D> {
+    /// Computes the arithmetic mean along the given axis, returning an array with that axis removed.
+    ///
+    /// Elements are promoted to `f64` like `mean().compute()`, which computes the mean over the
+    /// whole array. Returns `ArrayError::EmptyArray` if the array has no elements.
+    pub fn mean_axis(&self, axis: usize) -> Result<Array<f64, D::Smaller>, ArrayError>
+    where
+        D: RemoveAxis,
+    {
+        self.check_axis(axis)?;
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        let len = self.shape.dims()[axis] as f64;
+        let mut means = self.fold_axis(axis, 0.0, |acc, &x| acc + x.into())?;
+        means.data.iter_mut().for_each(|sum| *sum /= len);
+        Ok(means)
+    }
+
+    /// Computes the variance of all elements as `sum((x - mean)^2) / (n - ddof)`.
+    ///
+    /// `ddof = 0` gives the population variance and `ddof = 1` the sample variance.
+    /// Returns `NaN` if `n - ddof` is not positive.
+    pub fn var(&self, ddof: usize) -> f64 {
+        let n = self.data.len();
+        if n <= ddof {
+            return f64::NAN;
+        }
+
+        let mean = self.data.iter().map(|&x| x.into()).sum::<f64>() / n as f64;
+        let squares: f64 = self
+            .data
+            .iter()
+            .map(|&x| {
+                let diff = x.into() - mean;
+                diff * diff
+            })
+            .sum();
+        squares / (n - ddof) as f64
+    }
+
+    /// Computes the standard deviation of all elements as the square root of `var(ddof)`.
+    pub fn std(&self, ddof: usize) -> f64 {
+        self.var(ddof).sqrt()
+    }
+
+    /// Computes the median of all elements, averaging the two middle values when the number of
+    /// elements is even. Returns `NaN` for an empty array or if any element is `NaN`.
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// Computes the `q`-th percentile of all elements, for `q` between 0 and 100, interpolating
+    /// linearly between the two nearest ranks of a sorted copy of the data. Returns `NaN` for an
+    /// empty array or if any element is `NaN`.
+    ///
+    /// Panics if `q` is outside `[0, 100]`.
+    pub fn percentile(&self, q: f64) -> f64 {
+        assert!(
+            (0.0..=100.0).contains(&q),
+            "percentile requires 0 <= q <= 100, got {}",
+            q
+        );
+
+        let mut sorted: Vec<f64> = self.data.iter().map(|&x| x.into()).collect();
+        if sorted.is_empty() || sorted.iter().any(|x| x.is_nan()) {
+            return f64::NAN;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let rank = q / 100.0 * (sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let fraction = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+    }
+}
+
+/// Returns `true` if the value is unordered with itself, which is the case for `NaN`.
+pub(crate) fn is_unordered<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
+    /// Returns the smallest element of the array, skipping `NaN` values, or `None` if the array
+    /// is empty or contains only `NaN`.
+    pub fn nanmin(&self) -> Option<T> {
+        self.data
+            .iter()
+            .copied()
+            .filter(|x| !is_unordered(x))
+            .reduce(|min, x| if x < min { x } else { min })
+    }
+
+    /// Returns the largest element of the array, skipping `NaN` values, or `None` if the array
+    /// is empty or contains only `NaN`.
+    pub fn nanmax(&self) -> Option<T> {
+        self.data
+            .iter()
+            .copied()
+            .filter(|x| !is_unordered(x))
+            .reduce(|max, x| if x > max { x } else { max })
+    }
+
+    /// Returns the smallest element of the array, or `None` if the array is empty.
+    /// Unlike `nanmin`, a `NaN` anywhere in the array propagates and is returned.
+    pub fn amin(&self) -> Option<T> {
+        match self.data.iter().find(|x| is_unordered(*x)) {
+            Some(&nan) => Some(nan),
+            None => self.nanmin(),
+        }
+    }
+
+    /// Returns the largest element of the array, or `None` if the array is empty.
+    /// Unlike `nanmax`, a `NaN` anywhere in the array propagates and is returned.
+    pub fn amax(&self) -> Option<T> {
+        match self.data.iter().find(|x| is_unordered(*x)) {
+            Some(&nan) => Some(nan),
+            None => self.nanmax(),
+        }
+    }
+
+    /// Returns the flat row-major index of the first smallest element, skipping `NaN` values,
+    /// or `None` if there is no such element.
+    pub fn argmin(&self) -> Option<usize> {
+        self.position_by(|x, best| x < best)
+    }
+
+    /// Returns the flat row-major index of the first largest element, skipping `NaN` values,
+    /// or `None` if there is no such element.
+    pub fn argmax(&self) -> Option<usize> {
+        self.position_by(|x, best| x > best)
+    }
+
+    /// Returns the coordinate of the first smallest element, skipping `NaN` values.
+    pub fn argmin_index(&self) -> Option<D> {
+        self.argmin().map(|i| self.shape.unravel(i))
+    }
+
+    /// Returns the coordinate of the first largest element, skipping `NaN` values.
+    pub fn argmax_index(&self) -> Option<D> {
+        self.argmax().map(|i| self.shape.unravel(i))
+    }
+
+    /// Returns the index of the first element that no later element replaces according to `better`.
+    fn position_by<F: Fn(&T, &T) -> bool>(&self, better: F) -> Option<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, x)| !is_unordered(*x))
+            .reduce(|best, item| if better(item.1, best.1) { item } else { best })
+            .map(|(i, _)| i)
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for Array<T, Ix<N>> {
+    type Output = T;
+
+    /// Returns a reference to the element at the given coordinate.
+    ///
+    /// Panics if the coordinate is out of bounds for the shape of the array.
+    fn index(&self, index: [usize; N]) -> &T {
+        match strided_offset(self.shape.dims(), self.strides.dims(), &index) {
+            Some(offset) => &self.data[offset],
+            None => panic!(
+                "Index {:?} is out of bounds for array with shape {:?}",
+                index,
+                self.shape.dims()
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for Array<T, Ix<N>> {
+    /// Returns a mutable reference to the element at the given coordinate.
+    ///
+    /// Panics if the coordinate is out of bounds for the shape of the array.
+    fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+        match strided_offset(self.shape.dims(), self.strides.dims(), &index) {
+            Some(offset) => &mut self.data[offset],
+            None => panic!(
+                "Index {:?} is out of bounds for array with shape {:?}",
+                index,
+                self.shape.dims()
+            ),
+        }
+    }
+}
+
+impl<T: PartialEq, D: Dimension> PartialEq for Array<T, D> {
+    /// Two arrays are equal when they have the same shape and equal elements at every position.
+    fn eq(&self, other: &Self) -> bool {
+        if self.shape.dims() != other.shape.dims() {
+            return false;
+        }
+        match self.order == other.order {
+            true => self.data == other.data,
+            false => self.iter().eq(other.iter()),
+        }
+    }
+}
+
+impl<T: Eq, D: Dimension> Eq for Array<T, D> {}
+
+impl<T> FromIterator<T> for Array<T, Ix<1>> {
+    /// Collects the elements of an iterator into a 1D array.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: Vec<T> = iter.into_iter().collect();
+        let len = data.len();
+        Array::from_parts(data, Shape::new(Ix::<1>::new([len])))
+    }
+}
+
+impl<T, D: Dimension> IntoIterator for Array<T, D> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the array and yields its elements by value in row-major order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_order(Order::RowMajor).data.into_iter()
+    }
+}
+
+impl<'a, T, D: Dimension> IntoIterator for &'a Array<T, D> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, D>;
+
+    /// Yields references to the elements in row-major order, like `Array::iter`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{E, PI, TAU};
 
-    use crate::{Dimension, Ix, Shape};
+    use crate::{Array, ArrayDyn, ArrayError, Dimension, Ix, IxDyn, Order, Shape, ShapeError};
 
     fn round_to_3dp(value: f64) -> f64 {
         (value * 1000.0).round() / 1000.0
@@ -486,14 +1312,41 @@ mod tests {
     }
 
     #[test]
-    fn max_i64_1d() {
-        let arr = arr![42, -17, 256, 3, 99, -8];
-        assert_eq!(arr.max().compute(), vec![256]);
+    fn array_creation_i64_4d() {
+        let arr = arr![[[[1, 2], [3, 4]]]];
+        let ix = Ix::<4>::new([1, 1, 2, 2]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 4);
+        assert_eq!(arr.shape().raw_dim().ndim(), 4);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.data(), &vec![1, 2, 3, 4]);
     }
 
     #[test]
-    fn max_f64_1d() {
-        let arr = arr![PI, 2.71, -1.0, 42.0, 0.98];
+    fn array_creation_f64_4d() {
+        let arr = arr![
+            [[[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]]],
+            [[[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]]
+        ];
+        let ix = Ix::<4>::new([2, 1, 2, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 12);
+        assert_eq!(arr.shape().raw_dim().ndim(), 4);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.data()[6], 7.7);
+    }
+
+    #[test]
+    fn max_i64_1d() {
+        let arr = arr![42, -17, 256, 3, 99, -8];
+        assert_eq!(arr.max().compute(), vec![256]);
+    }
+
+    #[test]
+    fn max_f64_1d() {
+        let arr = arr![PI, 2.71, -1.0, 42.0, 0.98];
         assert_eq!(arr.max().compute(), vec![42.0]);
     }
 
@@ -1012,4 +1865,1164 @@ mod tests {
         assert_vec_approx_eq(arr.mean().axis(1).compute(), expected_mean_axis_1);
         assert_vec_approx_eq(arr.mean().axis(2).compute(), expected_mean_axis_2);
     }
+
+    #[test]
+    fn full_macro_u8_1d() {
+        let arr = full!(u8, 255, 4);
+        let ix = Ix::<1>::new([4]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 4);
+        assert_eq!(arr.shape().raw_dim().ndim(), 1);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.data(), &vec![255u8; 4]);
+    }
+
+    #[test]
+    fn full_macro_i64_2d() {
+        let arr = full!(i64, -1, 3, 2);
+        let ix = Ix::<2>::new([3, 2]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 6);
+        assert_eq!(arr.shape().raw_dim().ndim(), 2);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.dtype(), "int64");
+        assert_eq!(arr.data(), &vec![-1i64; 6]);
+    }
+
+    #[test]
+    fn full_macro_f64_3d() {
+        let arr = full!(f64, PI, 2, 2, 3);
+        let ix = Ix::<3>::new([2, 2, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 12);
+        assert_eq!(arr.shape().raw_dim().ndim(), 3);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.dtype(), "float64");
+        assert_eq!(arr.data(), &vec![PI; 12]);
+    }
+
+    #[test]
+    fn full_macro_evaluates_value_once() {
+        let mut calls = 0;
+        let arr = full!(
+            i64,
+            {
+                calls += 1;
+                7
+            },
+            2,
+            2
+        );
+
+        assert_eq!(calls, 1);
+        assert_eq!(arr.data(), &vec![7i64; 4]);
+    }
+
+    #[test]
+    fn eye_i64_3x3() {
+        let arr = eye!(i64, 3);
+        let ix = Ix::<2>::new([3, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.dtype(), "int64");
+        assert_eq!(arr.data(), &vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn eye_f64_1x1() {
+        let arr = Array::<f64, Ix<2>>::eye(1);
+
+        assert_eq!(arr.shape().dims(), &[1, 1]);
+        assert_eq!(arr.data(), &vec![1.0]);
+    }
+
+    #[test]
+    fn eye_empty() {
+        let arr = Array::<i64, Ix<2>>::eye(0);
+
+        assert_eq!(arr.shape().dims(), &[0, 0]);
+        assert!(arr.data().is_empty());
+    }
+
+    #[test]
+    fn arange_i64_1d() {
+        let arr = arange!(0i64, 10, 3);
+        let ix = Ix::<1>::new([4]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.dtype(), "int64");
+        assert_eq!(arr.data(), &vec![0, 3, 6, 9]);
+        assert_eq!(arange!(4i64).data(), &vec![0, 1, 2, 3]);
+        assert_eq!(arange!(2i64, 5).data(), &vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn arange_f64_1d() {
+        let arr = arange!(0.0, 1.0, 0.25);
+        assert_eq!(arr.shape().dims(), &[4]);
+        assert_vec_approx_eq(arr.data().clone(), vec![0.0, 0.25, 0.5, 0.75]);
+
+        let arr = arange!(1.0, -0.5, -0.5);
+        assert_vec_approx_eq(arr.data().clone(), vec![1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn arange_mismatched_step_sign_is_empty() {
+        let arr = arange!(0i64, 5, -1);
+        assert_eq!(arr.shape().dims(), &[0]);
+        assert!(arr.data().is_empty());
+
+        let arr = arange!(5u8, 2, 1);
+        assert!(arr.data().is_empty());
+    }
+
+    #[test]
+    fn arange_zero_step() {
+        assert!(Array::arange(0i64, 5, 0).is_err());
+    }
+
+    #[test]
+    fn arange_rejects_unbounded_length() {
+        for (stop, step) in [(f64::INFINITY, 1.0), (f64::NAN, 1.0), (1.0, 1e-300)] {
+            assert!(matches!(
+                Array::arange(0.0, stop, step),
+                Err(ArrayError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn linspace_f64_1d() {
+        let arr = Array::linspace(0.0, 1.0, 5);
+        let ix = Ix::<1>::new([5]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.dtype(), "float64");
+        assert_eq!(arr.data(), &vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn linspace_ends_exactly_at_stop() {
+        let arr = Array::linspace(0.1, 0.7, 7);
+        assert_eq!(arr.data()[6], 0.7);
+        assert_vec_approx_eq(arr.data().clone(), vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7]);
+    }
+
+    #[test]
+    fn linspace_single_and_empty() {
+        assert_eq!(Array::linspace(2.5, 10.0, 1).data(), &vec![2.5]);
+
+        let arr = Array::linspace(0.0, 1.0, 0);
+        assert_eq!(arr.shape().dims(), &[0]);
+        assert!(arr.data().is_empty());
+    }
+
+    #[test]
+    fn reshape_i64_1d_to_2d() {
+        let arr = arr![1, 2, 3, 4, 5, 6]
+            .reshape(Ix::<2>::new([2, 3]))
+            .unwrap();
+        let ix = Ix::<2>::new([2, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.data(), &vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn reshape_f64_3d_to_1d() {
+        let arr = arr![
+            [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]],
+            [[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]
+        ];
+        let reshaped = arr.reshape(Ix::<1>::new([12])).unwrap();
+
+        assert_eq!(reshaped.shape().dims(), &[12]);
+        assert_eq!(reshaped.data()[11], 12.2);
+    }
+
+    #[test]
+    fn reshape_size_mismatch() {
+        let result = arr![1, 2, 3, 4, 5, 6].reshape(Ix::<2>::new([4, 2]));
+        assert!(matches!(result, Err(ShapeError::IncompatibleShape(_))));
+    }
+
+    #[test]
+    fn flatten_i64_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+        let flat = arr.flatten();
+        let ix = Ix::<1>::new([4]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", flat.shape()), format!("{:?}", shape));
+        assert_eq!(flat.data(), &vec![1, 2, 3, 4]);
+        assert_eq!(arr.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn flatten_f64_3d() {
+        let arr = arr![
+            [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]],
+            [[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]
+        ];
+        let flat = arr.flatten();
+
+        assert_eq!(flat.shape().dims(), &[12]);
+        assert_eq!(flat.data(), arr.data());
+    }
+
+    #[test]
+    fn ravel_i64_3d() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]].ravel();
+
+        assert_eq!(arr.shape().dims(), &[12]);
+        assert_eq!(arr.data(), &(1..=12).collect::<Vec<i64>>());
+    }
+
+    #[test]
+    fn transpose_i64_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]].transpose();
+        let ix = Ix::<2>::new([3, 2]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert_eq!(arr.data(), &vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn transpose_col_major() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]].to_order(Order::ColMajor);
+
+        assert_eq!(arr.transpose(), arr![[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn transpose_f64_2d_round_trip() {
+        let arr = arr![[TAU, -PI, 1.61], [E, 0.98, -7.42], [4.67, -0.45, 8.88]];
+        let transposed = arr.transpose();
+
+        assert_eq!(transposed.data()[1], E);
+        assert_eq!(transposed.transpose().data(), arr.data());
+    }
+
+    #[test]
+    fn sum_i64_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.sum(), 10);
+    }
+
+    #[test]
+    fn sum_f64_3d() {
+        let arr = arr![[[0.5, 1.5], [2.5, 3.5]], [[-1.0, -2.0], [4.0, 1.0]]];
+        assert_eq!(arr.sum(), 10.0);
+    }
+
+    #[test]
+    fn sum_zeros_and_empty() {
+        assert_eq!(zeros!(i32, 5).sum(), 0);
+        assert_eq!(Array::<f64, Ix<2>>::eye(0).sum(), 0.0);
+    }
+
+    #[test]
+    fn sum_axis_i64_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        let axis_0 = arr.sum_axis(0).unwrap();
+        assert_eq!(
+            format!("{:?}", axis_0.shape()),
+            format!("{:?}", Ix::<1>::new([3]))
+        );
+        assert_eq!(axis_0.data(), &vec![5, 7, 9]);
+
+        let axis_1 = arr.sum_axis(1).unwrap();
+        assert_eq!(axis_1.shape().dims(), &[2]);
+        assert_eq!(axis_1.data(), &vec![6, 15]);
+    }
+
+    #[test]
+    fn sum_axis_i64_3d() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
+
+        let axis_0 = arr.sum_axis(0).unwrap();
+        assert_eq!(axis_0.shape().dims(), &[2, 3]);
+        assert_eq!(axis_0.data(), &vec![8, 10, 12, 14, 16, 18]);
+
+        let axis_1 = arr.sum_axis(1).unwrap();
+        assert_eq!(axis_1.shape().dims(), &[2, 3]);
+        assert_eq!(axis_1.data(), &vec![5, 7, 9, 17, 19, 21]);
+
+        let axis_2 = arr.sum_axis(2).unwrap();
+        assert_eq!(axis_2.shape().dims(), &[2, 2]);
+        assert_eq!(axis_2.data(), &vec![6, 15, 24, 33]);
+    }
+
+    #[test]
+    fn sum_axis_f64_1d() {
+        let arr = arr![1.5, 2.5, 3.0];
+        let total = arr.sum_axis(0).unwrap();
+
+        assert!(total.shape().dims().is_empty());
+        assert_eq!(total.data(), &vec![7.0]);
+    }
+
+    #[test]
+    fn sum_axis_out_of_bounds() {
+        let arr = arr![[1, 2], [3, 4]];
+        let err = arr.sum_axis(2).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid axis specified: Axis 2 is out of bounds for array with 2 dimensions"
+        );
+    }
+
+    #[test]
+    fn mean_axis_i32_2d() {
+        let arr = arr![[1, 5, 3], [4, 2, 6], [0, 9, 8]];
+
+        let axis_0 = arr.mean_axis(0).unwrap();
+        assert_eq!(axis_0.shape().dims(), &[3]);
+        assert_vec_approx_eq(axis_0.data().clone(), vec![1.667, 5.333, 5.667]);
+
+        let axis_1 = arr.mean_axis(1).unwrap();
+        assert_vec_approx_eq(axis_1.data().clone(), vec![3.0, 4.0, 5.667]);
+    }
+
+    #[test]
+    fn mean_axis_f64_3d() {
+        let arr = arr![
+            [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]],
+            [[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]
+        ];
+
+        let axis_2 = arr.mean_axis(2).unwrap();
+        assert_eq!(axis_2.shape().dims(), &[2, 2]);
+        assert_vec_approx_eq(axis_2.data().clone(), arr.mean().axis(2).compute());
+    }
+
+    #[test]
+    fn mean_axis_empty_and_invalid() {
+        let empty = Array::<f64, Ix<2>>::eye(0);
+        assert!(matches!(empty.mean_axis(0), Err(ArrayError::EmptyArray)));
+
+        let arr = arr![1.0, 2.0];
+        assert!(matches!(arr.mean_axis(1), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn nanmin_nanmax_i64_mixed_sign() {
+        let arr = arr![[42, -17, 256], [3, 99, -8]];
+
+        assert_eq!(arr.nanmin(), Some(-17));
+        assert_eq!(arr.nanmax(), Some(256));
+        assert_eq!(arr.amin(), Some(-17));
+        assert_eq!(arr.amax(), Some(256));
+    }
+
+    #[test]
+    fn nanmin_nanmax_all_equal() {
+        let arr = full!(f64, 2.5, 2, 2);
+
+        assert_eq!(arr.nanmin(), Some(2.5));
+        assert_eq!(arr.nanmax(), Some(2.5));
+    }
+
+    #[test]
+    fn nanmin_nanmax_f64_with_nan() {
+        let arr = arr![3.0, f64::NAN, -1.0, 7.5];
+
+        assert_eq!(arr.nanmin(), Some(-1.0));
+        assert_eq!(arr.nanmax(), Some(7.5));
+        assert!(arr.amin().unwrap().is_nan());
+        assert!(arr.amax().unwrap().is_nan());
+        assert_eq!(arr![f64::NAN].nanmin(), None);
+    }
+
+    #[test]
+    fn nanmin_nanmax_empty() {
+        let arr = Array::<i64, Ix<2>>::eye(0);
+
+        assert_eq!(arr.nanmin(), None);
+        assert_eq!(arr.nanmax(), None);
+        assert_eq!(arr.amin(), None);
+        assert_eq!(arr.amax(), None);
+    }
+
+    #[test]
+    fn argmin_argmax_i64_1d() {
+        let arr = arr![42, -17, 256, 3, 256, -17];
+
+        assert_eq!(arr.argmin(), Some(1));
+        assert_eq!(arr.argmax(), Some(2));
+    }
+
+    #[test]
+    fn argmin_argmax_index_f64_2d() {
+        let arr = arr![[PI, -2.71, 1.61], [2.72, 0.98, -7.42], [4.67, -0.45, 8.88]];
+
+        assert_eq!(arr.argmin(), Some(5));
+        assert_eq!(arr.argmax(), Some(8));
+        assert_eq!(arr.argmin_index().unwrap().dims(), &[1, 2]);
+        assert_eq!(arr.argmax_index().unwrap().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn argmax_index_i64_3d() {
+        let arr = arr![
+            [[101, 202, 303], [404, 505, 606]],
+            [[-707, -808, -909], [111, 222, 333]]
+        ];
+
+        assert_eq!(arr.argmax_index().unwrap().dims(), &[0, 1, 2]);
+        assert_eq!(arr.argmin_index().unwrap().dims(), &[1, 0, 2]);
+    }
+
+    #[test]
+    fn argmin_argmax_empty_and_nan() {
+        let empty = Array::<f64, Ix<2>>::eye(0);
+        assert_eq!(empty.argmin(), None);
+        assert_eq!(empty.argmax_index().map(|ix| ix.dims().to_vec()), None);
+
+        let arr = arr![f64::NAN, 2.0, -1.0];
+        assert_eq!(arr.argmin(), Some(2));
+        assert_eq!(arr.argmax(), Some(1));
+    }
+
+    #[test]
+    fn prod_i64_1d() {
+        assert_eq!(arr![1, 2, 3, 4].prod(), 24);
+        assert_eq!(arr![7, 0, 9].prod(), 0);
+    }
+
+    #[test]
+    fn prod_f64_empty() {
+        let empty = Array::<f64, Ix<2>>::eye(0);
+        assert_eq!(empty.prod(), 1.0);
+    }
+
+    #[test]
+    fn prod_axis_i64_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+
+        let axis_0 = arr.prod_axis(0).unwrap();
+        assert_eq!(axis_0.shape().dims(), &[2]);
+        assert_eq!(axis_0.data(), &vec![3, 8]);
+
+        let axis_1 = arr.prod_axis(1).unwrap();
+        assert_eq!(axis_1.data(), &vec![2, 12]);
+
+        assert!(arr.prod_axis(2).is_err());
+    }
+
+    #[test]
+    fn var_std_f64_1d() {
+        let arr = arr![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        assert_eq!(arr.var(0), 4.0);
+        assert_eq!(arr.std(0), 2.0);
+        assert_eq!(round_to_3dp(arr.var(1)), 4.571);
+        assert_eq!(round_to_3dp(arr.std(1)), 2.138);
+    }
+
+    #[test]
+    fn var_std_i32_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+
+        assert_eq!(arr.var(0), 1.25);
+        assert_eq!(round_to_3dp(arr.var(1)), 1.667);
+        assert_eq!(round_to_3dp(arr.std(0)), 1.118);
+    }
+
+    #[test]
+    fn var_insufficient_ddof_is_nan() {
+        let arr = arr![PI];
+
+        assert_eq!(arr.var(0), 0.0);
+        assert!(arr.var(1).is_nan());
+        assert!(arr.std(2).is_nan());
+    }
+
+    #[test]
+    fn get_i64_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(arr.get(Ix::<2>::new([1, 2])), Some(&6));
+        assert_eq!(arr.get(Ix::<2>::new([0, 1])), Some(&2));
+        assert_eq!(arr.get(Ix::<2>::new([2, 0])), None);
+        assert_eq!(arr.get(Ix::<2>::new([0, 3])), None);
+    }
+
+    #[test]
+    fn get_mut_f64_3d() {
+        let mut arr = arr![[[PI, E], [TAU, 1.0]], [[2.0, 3.0], [4.0, 5.0]]];
+
+        *arr.get_mut(Ix::<3>::new([1, 0, 1])).unwrap() = -1.0;
+        assert_eq!(arr.data()[5], -1.0);
+        assert!(arr.get_mut(Ix::<3>::new([0, 2, 0])).is_none());
+    }
+
+    #[test]
+    fn shape_strides() {
+        assert_eq!(
+            Shape::new(Ix::<3>::new([2, 3, 4])).strides(),
+            vec![12, 4, 1]
+        );
+        assert_eq!(Shape::new(Ix::<1>::new([5])).strides(), vec![1]);
+    }
+
+    #[test]
+    fn index_i32_2d() {
+        let mut a = zeros!(i32, 2, 2);
+        a[[0, 1]] = 5;
+
+        assert_eq!(a[[0, 1]], 5);
+        assert_eq!(a.data(), &vec![0, 5, 0, 0]);
+    }
+
+    #[test]
+    fn index_f64_3d() {
+        let arr = arr![[[PI, E], [TAU, 1.0]], [[2.0, 3.0], [4.0, 5.0]]];
+        assert_eq!(arr[[0, 1, 0]], TAU);
+        assert_eq!(arr[[1, 1, 1]], 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [2, 0] is out of bounds for array with shape [2, 2]")]
+    fn index_out_of_bounds_panics() {
+        let arr = arr![[1, 2], [3, 4]];
+        let _ = arr[[2, 0]];
+    }
+
+    #[test]
+    fn iter_i32_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(arr.iter().copied().sum::<i32>(), 21);
+        assert_eq!(
+            arr.iter().collect::<Vec<_>>(),
+            arr.flatten().data().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iter_f64_3d_order() {
+        let arr = arr![[[PI, E], [TAU, 1.0]], [[2.0, 3.0], [4.0, 5.0]]];
+        let values: Vec<f64> = arr.iter().copied().collect();
+
+        assert_eq!(values, vec![PI, E, TAU, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn iter_mut_i32_2d() {
+        let mut arr = arr![[1, 2, 3], [4, 5, 6]];
+        for x in arr.iter_mut() {
+            *x *= 2;
+        }
+
+        assert_eq!(arr.data(), &vec![2, 4, 6, 8, 10, 12]);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn iter_mut_f64_1d_zip() {
+        let mut arr = arr![0.5, 1.5, 2.5];
+        let offsets = [1.0, -1.0, 0.5];
+        arr.iter_mut()
+            .zip(offsets)
+            .for_each(|(x, offset)| *x += offset);
+
+        assert_eq!(arr.data(), &vec![1.5, 0.5, 3.0]);
+    }
+
+    #[test]
+    fn indexed_iter_i64_2d_non_square() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let items: Vec<(Vec<usize>, i32)> = arr
+            .indexed_iter()
+            .map(|(ix, &x)| (ix.dims().to_vec(), x))
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                (vec![0, 0], 1),
+                (vec![0, 1], 2),
+                (vec![0, 2], 3),
+                (vec![1, 0], 4),
+                (vec![1, 1], 5),
+                (vec![1, 2], 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn indexed_iter_f64_3d() {
+        let arr = arr![[[0.5], [1.5], [2.5]], [[3.5], [4.5], [5.5]]];
+        let items: Vec<(Vec<usize>, f64)> = arr
+            .indexed_iter()
+            .map(|(ix, &x)| (ix.dims().to_vec(), x))
+            .collect();
+
+        assert_eq!(items.len(), 6);
+        assert_eq!(items[2], (vec![0, 2, 0], 2.5));
+        assert_eq!(items[3], (vec![1, 0, 0], 3.5));
+        assert_eq!(items[5], (vec![1, 2, 0], 5.5));
+        for (ix, x) in arr.indexed_iter() {
+            assert_eq!(arr.get(ix), Some(x));
+        }
+    }
+
+    #[test]
+    fn map_i32_to_f64_1d() {
+        let arr = arr![1, 2, 3];
+        let halves = arr.map(|&x| x as f64 * 0.5);
+
+        assert_eq!(halves.data(), &vec![0.5, 1.0, 1.5]);
+        assert_eq!(halves.shape().dims(), &[3]);
+        assert_eq!(arr.data(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn map_f64_to_bool_2d() {
+        let arr = arr![[PI, -E], [-TAU, 1.0]];
+        let positive = arr.map(|&x| x > 0.0);
+
+        assert_eq!(positive.shape().dims(), &[2, 2]);
+        assert_eq!(positive.data(), &vec![true, false, false, true]);
+    }
+
+    #[test]
+    fn map_inplace_f64_2d_clamp_negatives() {
+        let mut arr = arr![[PI, -E, 0.5], [-TAU, 1.0, -0.25]];
+        arr.map_inplace(|x| *x = x.max(0.0));
+
+        assert_eq!(arr.data(), &vec![PI, 0.0, 0.5, 0.0, 1.0, 0.0]);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn map_inplace_f64_abs() {
+        let mut arr: Array<f64, Ix<1>> = arr![-1.5, 2.5, -3.5];
+        arr.map_inplace(|x| *x = x.abs());
+
+        assert_eq!(arr.data(), &vec![1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn mapv_inplace_i32_2d() {
+        let mut arr = arr![[1, -2], [3, -4]];
+        arr.mapv_inplace(|x| x * x - 1);
+
+        assert_eq!(arr.data(), &vec![0, 3, 8, 15]);
+        assert_eq!(arr.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn into_raw_vec_and_slices_i32_2d() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4]);
+
+        arr.as_mut_slice()[3] = 40;
+        assert_eq!(arr[[1, 1]], 40);
+        assert_eq!(arr.into_raw_vec(), vec![1, 2, 3, 40]);
+    }
+
+    #[test]
+    fn cast_i32_to_f64_1d() {
+        let arr = arr![1, 2, 3];
+        let floats: Array<f64, Ix<1>> = arr.cast();
+
+        assert_eq!(floats.data(), &vec![1.0, 2.0, 3.0]);
+        assert_eq!(floats.shape().dims(), &[3]);
+    }
+
+    #[test]
+    fn cast_lossy_f64_to_i32_2d() {
+        let arr = arr![[PI, -E], [2.99, f64::MAX]];
+        let ints: Array<i32, Ix<2>> = arr.cast_lossy();
+
+        assert_eq!(ints.data(), &vec![3, -2, 2, i32::MAX]);
+        assert_eq!(ints.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn cast_lossy_i64_to_u8_wraps() {
+        let arr = arr![255_i64, 256, -1];
+        let bytes: Array<u8, Ix<1>> = arr.cast_lossy();
+
+        assert_eq!(bytes.data(), &vec![255, 0, 255]);
+    }
+
+    #[test]
+    fn cumsum_i64_1d() {
+        let arr = arr![1, 2, 3, 4];
+        let result = arr.cumsum(0).unwrap();
+
+        assert_eq!(result.data(), &vec![1, 3, 6, 10]);
+        assert_eq!(result.shape().dims(), &[4]);
+    }
+
+    #[test]
+    fn cumsum_i64_2d_both_axes() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(arr.cumsum(0).unwrap().data(), &vec![1, 2, 3, 5, 7, 9]);
+        assert_eq!(arr.cumsum(1).unwrap().data(), &vec![1, 3, 6, 4, 9, 15]);
+        assert!(arr.cumsum(2).is_err());
+    }
+
+    #[test]
+    fn cumsum_f64_3d_middle_axis() {
+        let arr = arr![[[0.5, 1.0], [1.5, 2.0]], [[2.5, 3.0], [3.5, 4.0]]];
+        let result = arr.cumsum(1).unwrap();
+
+        assert_eq!(result.data(), &vec![0.5, 1.0, 2.0, 3.0, 2.5, 3.0, 6.0, 7.0]);
+    }
+
+    #[test]
+    fn cumprod_i64_1d() {
+        let arr = arr![1, 2, 3, 4];
+        assert_eq!(arr.cumprod(0).unwrap().data(), &vec![1, 2, 6, 24]);
+    }
+
+    #[test]
+    fn cumprod_f64_2d_axis_1() {
+        let arr = arr![[0.5, 2.0, 3.0], [4.0, 0.25, -1.0]];
+        let result = arr.cumprod(1).unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 3]);
+        assert_eq!(result.data(), &vec![0.5, 1.0, 3.0, 4.0, 1.0, -1.0]);
+        assert_eq!(
+            arr.cumprod(0).unwrap().data(),
+            &vec![0.5, 2.0, 3.0, 2.0, 0.5, -3.0]
+        );
+    }
+
+    #[test]
+    fn cummax_cummin_i64_1d() {
+        let arr = arr![1, 3, 2, 5, 4];
+
+        assert_eq!(arr.cummax(0).unwrap().data(), &vec![1, 3, 3, 5, 5]);
+        assert_eq!(arr.cummin(0).unwrap().data(), &vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn cummax_cummin_f64_2d() {
+        let arr = arr![[2.0, -1.0, 3.0], [0.5, 4.0, f64::NAN]];
+
+        assert_eq!(
+            arr.cummin(1).unwrap().data()[..5],
+            [2.0, -1.0, -1.0, 0.5, 0.5]
+        );
+        assert!(arr.cummin(1).unwrap()[[1, 2]].is_nan());
+        assert_eq!(
+            arr.cummax(0).unwrap().data()[..5],
+            [2.0, -1.0, 3.0, 2.0, 4.0]
+        );
+        assert!(arr.cummax(0).unwrap()[[1, 2]].is_nan());
+        assert!(matches!(arr.cummax(2), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn axis_reductions_col_major() {
+        let row = arr![[1, 2, 3], [4, 5, 6]];
+        let col = row.to_order(Order::ColMajor);
+
+        assert_eq!(col.sum_axis(0).unwrap().data(), &vec![5, 7, 9]);
+        assert_eq!(col.prod_axis(1).unwrap().data(), &vec![6, 120]);
+        assert_eq!(col.count_nonzero_axis(1).unwrap().data(), &vec![3, 3]);
+        assert_eq!(col.mean_axis(0).unwrap().data(), &vec![2.5, 3.5, 4.5]);
+        assert_eq!(col.max_compute(Some(0)).unwrap(), vec![4, 5, 6]);
+        assert_eq!(col.min_compute(Some(1)).unwrap(), vec![1, 4]);
+        assert_eq!(col.mean_compute(Some(1)).unwrap(), vec![2.0, 5.0]);
+
+        let col_3d = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]].to_order(Order::ColMajor);
+        assert_eq!(col_3d.max_compute(Some(2)).unwrap(), vec![2, 4, 6, 8]);
+        assert_eq!(col_3d.min_compute(Some(1)).unwrap(), vec![1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn cumulative_ops_col_major() {
+        let col = arr![[1, 3, 2], [4, 0, 6]].to_order(Order::ColMajor);
+
+        assert_eq!(col.cumsum(1).unwrap(), arr![[1, 4, 6], [4, 4, 10]]);
+        assert_eq!(col.cumprod(0).unwrap(), arr![[1, 3, 2], [4, 0, 12]]);
+        assert_eq!(col.cummax(1).unwrap(), arr![[1, 3, 3], [4, 4, 6]]);
+        assert_eq!(col.cummin(0).unwrap(), arr![[1, 3, 2], [1, 0, 2]]);
+    }
+
+    #[test]
+    fn argmax_col_major() {
+        let col = arr![[1, 9, 3], [4, 5, 0]].to_order(Order::ColMajor);
+
+        assert_eq!(col.argmax(), Some(1));
+        assert_eq!(col.argmin(), Some(5));
+        assert_eq!(col.argmax_index().unwrap().dims(), &[0, 1]);
+    }
+
+    #[test]
+    fn fill_i64_2d() {
+        let mut arr = arr![[1, 2, 3], [4, 5, 6]];
+        arr.fill(0);
+
+        assert_eq!(arr.data(), &vec![0; 6]);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn fill_f64_1d() {
+        let mut arr = arr![PI, E, TAU];
+        arr.fill(-1.5);
+        assert_eq!(arr.data(), &vec![-1.5, -1.5, -1.5]);
+    }
+
+    #[test]
+    fn ixdyn_array_2d_to_fixed() {
+        let shape = Shape::new(IxDyn::new(&[2, 3]));
+        let arr: ArrayDyn<i64> = Array::new(vec![1, 2, 3, 4, 5, 6], shape).unwrap();
+
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.shape().strides(), vec![3, 1]);
+        assert_eq!(arr.get(IxDyn::new(&[1, 2])), Some(&6));
+        assert_eq!(arr.sum_axis(0).unwrap().data(), &vec![5, 7, 9]);
+
+        let fixed: Array<i64, Ix<2>> = arr.into_dimensionality().unwrap();
+        assert_eq!(fixed[[1, 0]], 4);
+        assert_eq!(fixed.shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn ixdyn_into_dyn_and_wrong_rank() {
+        let arr = arr![[0.5, 1.5], [2.5, 3.5]].into_dyn();
+        assert_eq!(arr.shape().raw_dim(), &IxDyn::new(&[2, 2]));
+
+        let err = arr.into_dimensionality::<Ix<3>>().unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+    }
+
+    #[test]
+    fn ixdyn_conversions_and_broadcasting() {
+        let ix: IxDyn = Ix::<3>::new([4, 1, 2]).into();
+        assert_eq!(ix.ndim(), 3);
+        assert!(Ix::<2>::try_from(ix.clone()).is_err());
+        assert_eq!(Ix::<3>::try_from(ix).unwrap().dims(), &[4, 1, 2]);
+
+        let matrix = arr![[1, 2], [3, 4]].into_dyn();
+        let sum = &matrix + &arr![10, 20];
+        assert_eq!(sum.shape().dims(), &[2, 2]);
+        assert_eq!(sum.data(), &vec![11, 22, 13, 24]);
+    }
+
+    #[test]
+    fn from_shape_fn_multiplication_table_2d() {
+        let table = Array::from_shape_fn(Shape::new(Ix::<2>::new([3, 4])), |ix| ix[0] * ix[1]);
+
+        assert_eq!(table.shape().dims(), &[3, 4]);
+        assert_eq!(table.data(), &vec![0, 0, 0, 0, 0, 1, 2, 3, 0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn from_shape_fn_f64_3d_row_major() {
+        let mut calls = Vec::new();
+        let arr = Array::from_shape_fn(Shape::new(Ix::<3>::new([2, 1, 2])), |ix| {
+            calls.push(ix.dims().to_vec());
+            (ix[0] * 10 + ix[2]) as f64 * 0.5
+        });
+
+        assert_eq!(
+            calls,
+            vec![vec![0, 0, 0], vec![0, 0, 1], vec![1, 0, 0], vec![1, 0, 1]]
+        );
+        assert_eq!(arr.data(), &vec![0.0, 0.5, 5.0, 5.5]);
+    }
+
+    #[test]
+    fn partial_eq_i64_2d() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(a.transpose().transpose(), a);
+        assert_ne!(a.transpose(), a);
+        assert_ne!(arr![[1, 2], [3, 5]], arr![[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn partial_eq_same_data_different_shape() {
+        let row = Array::new(vec![1, 2, 3, 4], Shape::new(Ix::<2>::new([1, 4]))).unwrap();
+        let square = Array::new(vec![1, 2, 3, 4], Shape::new(Ix::<2>::new([2, 2]))).unwrap();
+
+        assert_ne!(row, square);
+        assert_ne!(arr![[1, 2], [3, 4]].into_dyn(), arr![1, 2, 3, 4].into_dyn());
+    }
+
+    #[test]
+    fn partial_eq_f64_nan() {
+        let a = arr![1.0, f64::NAN];
+        assert_ne!(a, arr![1.0, f64::NAN]);
+        assert_eq!(arr![0.5, -1.5], arr![0.5, -1.5]);
+    }
+
+    #[test]
+    fn introspection_2x3() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(arr.ndim(), 2);
+        assert_eq!(arr.len(), 6);
+        assert!(!arr.is_empty());
+        assert_eq!(arr.shape(), &[2, 3]);
+        assert_eq!(*arr.shape(), [2, 3][..]);
+        assert_ne!(arr.shape(), &[3, 2]);
+    }
+
+    #[test]
+    fn introspection_empty_and_dyn() {
+        let empty = Array::<f64, Ix<2>>::new(vec![], Shape::new(Ix::<2>::new([0, 4]))).unwrap();
+        assert_eq!(empty.ndim(), 2);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+
+        let arr = arr![[[1.5], [2.5]]].into_dyn();
+        assert_eq!(arr.ndim(), 3);
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.shape(), &[1, 2, 1]);
+    }
+
+    #[test]
+    fn strides_3x4_and_dyn() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<2>::new([3, 4])), |ix| ix[0] * 4 + ix[1]);
+        assert_eq!(arr.strides(), &[4, 1]);
+        assert_eq!(arr.get(Ix::new([2, 1])), Some(&9));
+        assert_eq!(arr[[1, 3]], 7);
+        assert_eq!(arr.view().strides(), arr.strides());
+
+        let cube = Array::new(vec![0.5; 24], Shape::new(IxDyn::new(&[2, 3, 4]))).unwrap();
+        assert_eq!(cube.strides(), &[12, 4, 1]);
+        assert_eq!(
+            arr![1, 2, 3]
+                .reshape(Ix::<2>::new([3, 1]))
+                .unwrap()
+                .strides(),
+            &[1, 1]
+        );
+    }
+
+    #[test]
+    fn order_col_major_3x2_strides_and_iter() {
+        let shape = Shape::new(Ix::<2>::new([3, 2]));
+        let arr = Array::new_with_order(vec![1, 3, 5, 2, 4, 6], shape, Order::ColMajor).unwrap();
+
+        assert_eq!(arr.order(), Order::ColMajor);
+        assert_eq!(arr.strides(), &[1, 3]);
+        assert_eq!(arr.as_slice(), &[1, 3, 5, 2, 4, 6]);
+        assert_eq!(
+            arr.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(arr[[2, 0]], 5);
+        assert_eq!(arr.get(Ix::new([0, 1])), Some(&2));
+
+        let indexed: Vec<_> = arr
+            .indexed_iter()
+            .map(|(ix, &x)| (ix[0], ix[1], x))
+            .collect();
+        assert_eq!(indexed[3], (1, 1, 4));
+        assert_eq!(arr, arr![[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(arr.to_string(), "[[1, 2],\n [3, 4],\n [5, 6]]");
+    }
+
+    #[test]
+    fn order_conversions_f64() {
+        let arr = arr![[0.5, 1.5, 2.5], [3.5, 4.5, 5.5]];
+        assert_eq!(arr.order(), Order::RowMajor);
+
+        let col = arr.to_order(Order::ColMajor);
+        assert_eq!(col.as_slice(), &[0.5, 3.5, 1.5, 4.5, 2.5, 5.5]);
+        assert_eq!(col.strides(), &[1, 2]);
+        assert_eq!(col, arr);
+
+        let doubled = col.map(|x| x * 2.0);
+        assert_eq!(doubled.order(), Order::ColMajor);
+        assert_eq!(doubled, arr.map(|x| x * 2.0));
+
+        let row = col.into_order(Order::RowMajor);
+        assert_eq!(row.strides(), &[3, 1]);
+        assert_eq!(row.as_slice(), arr.as_slice());
+    }
+
+    #[test]
+    fn order_col_major_iter_mut_and_ops() {
+        let shape = Shape::new(Ix::<2>::new([2, 2]));
+        let mut arr = Array::new_with_order(vec![1, 3, 2, 4], shape, Order::ColMajor).unwrap();
+        for (i, x) in arr.iter_mut().enumerate() {
+            *x += i as i64 * 10;
+        }
+        assert_eq!(arr, arr![[1, 12], [23, 34]]);
+
+        let sum = &arr + &arr![[1, 1], [1, 1]];
+        assert_eq!(sum, arr![[2, 13], [24, 35]]);
+        assert_eq!((&arr + &arr).order(), Order::ColMajor);
+        assert_eq!(&arr * 2, arr![[2, 24], [46, 68]]);
+        assert_eq!(
+            arr.reshape(Ix::<1>::new([4])).unwrap().data(),
+            &vec![1, 12, 23, 34]
+        );
+    }
+
+    #[test]
+    fn new_zeros_i32_2x3() {
+        let arr = Array::<i32, Ix<2>>::new_zeros(Shape::new(Ix::<2>::new([2, 3])));
+
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![0; 6]);
+    }
+
+    #[test]
+    fn new_ones_f64_runtime_shapes() {
+        let dims = vec![2, 1, 3];
+        let arr = Array::<f64, IxDyn>::new_ones(Shape::new(IxDyn::new(&dims)));
+        assert_eq!(arr.shape().dims(), &[2, 1, 3]);
+        assert_eq!(arr.data(), &vec![1.0; 6]);
+
+        let arr = Array::<u8, Ix<1>>::new_ones(Shape::new(Ix::<1>::new([0])));
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn full_string_2d_and_matches_macro() {
+        let arr = Array::full(Shape::new(Ix::<2>::new([2, 2])), String::from("ab"));
+        assert_eq!(arr.shape().dims(), &[2, 2]);
+        assert!(arr.iter().all(|s| s == "ab"));
+
+        let arr = Array::full(Shape::new(Ix::<3>::new([2, 1, 3])), -1.5);
+        assert_eq!(arr, full!(f64, -1.5, 2, 1, 3));
+    }
+
+    #[test]
+    fn from_iter_shape_i64_2d() {
+        let arr = Array::from_iter_shape((1..=6).map(|x| x * x), Shape::new(Ix::<2>::new([2, 3])))
+            .unwrap();
+
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr, arr![[1, 4, 9], [16, 25, 36]]);
+    }
+
+    #[test]
+    fn from_iter_shape_f64_3d_and_empty() {
+        let values = [0.5, 1.5, 2.5, 3.5];
+        let arr = Array::from_iter_shape(values, Shape::new(Ix::<3>::new([2, 1, 2]))).unwrap();
+        assert_eq!(arr.data(), &values.to_vec());
+
+        let empty = Array::from_iter_shape(Vec::<f64>::new(), Shape::new(Ix::<2>::new([0, 3])));
+        assert!(empty.unwrap().is_empty());
+    }
+
+    #[test]
+    fn from_iter_shape_length_mismatch() {
+        let err = Array::from_iter_shape(0..5, Shape::new(Ix::<2>::new([2, 3]))).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: iterator yielded 5 elements, but shape [2, 3] holds 6"
+        );
+        assert!(Array::from_iter_shape(0..7, Shape::new(Ix::<2>::new([2, 3]))).is_err());
+    }
+
+    #[test]
+    fn from_iterator_collects_1d() {
+        let arr: Array<i64, Ix<1>> = (0..4).map(|x| x * 10).collect();
+
+        assert_eq!(arr.shape().dims(), &[4]);
+        assert_eq!(arr, arr![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn into_iter_owned_i64_2d() {
+        assert_eq!(
+            arr![[1, 2], [3, 4]].into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        let col = arr![[1, 2], [3, 4]].to_order(Order::ColMajor);
+        assert_eq!(col.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        let mut words = Vec::new();
+        for s in arr![String::from("a"), String::from("b")] {
+            words.push(s);
+        }
+        assert_eq!(words, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn into_iter_borrowed_f64() {
+        let arr = arr![[0.5, 1.5], [2.5, 3.5]];
+        let mut total = 0.0;
+        for x in &arr {
+            total += x;
+        }
+
+        assert_eq!(total, 8.0);
+        assert_eq!((&arr).into_iter().count(), 4);
+    }
+
+    #[test]
+    fn count_nonzero_i64_2d() {
+        let arr = arr![[0, 1], [2, 0]];
+        assert_eq!(arr.count_nonzero(), 2);
+
+        let arr = arr![[0, 1, 3], [2, 0, 4]];
+        assert_eq!(arr.count_nonzero_axis(0).unwrap().data(), &vec![1, 1, 2]);
+        assert_eq!(arr.count_nonzero_axis(1).unwrap().data(), &vec![2, 2]);
+        assert!(matches!(
+            arr.count_nonzero_axis(2),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn count_nonzero_f64_signed_zero_and_nan() {
+        let arr = arr![0.0, -0.0, 0.5, f64::NAN, -1.5];
+        assert_eq!(arr.count_nonzero(), 3);
+    }
+
+    #[test]
+    fn median_odd_and_even_lengths() {
+        assert_eq!(arr![7, 1, 3].median(), 3.0);
+        assert_eq!(arr![[4, 1], [3, 2]].median(), 2.5);
+        assert_eq!(arr![-0.5].median(), -0.5);
+
+        let arr = arr![5.5, 1.5, 3.5, 9.5];
+        assert_eq!(arr.median(), 4.5);
+        assert_eq!(arr.data(), &vec![5.5, 1.5, 3.5, 9.5]);
+    }
+
+    #[test]
+    fn percentile_linear_interpolation() {
+        let arr = arr![10, 40, 20, 30, 50];
+        assert_eq!(arr.percentile(0.0), 10.0);
+        assert_eq!(arr.percentile(25.0), 20.0);
+        assert_eq!(arr.percentile(100.0), 50.0);
+        assert_eq!(arr.percentile(90.0), 46.0);
+
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert_eq!(arr.percentile(50.0), 2.5);
+        assert_eq!(arr.percentile(75.0), 3.25);
+    }
+
+    #[test]
+    fn percentile_empty_and_nan() {
+        let empty = Array::<f64, Ix<1>>::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(empty.median().is_nan());
+        assert!(arr![1.0, f64::NAN, 3.0].percentile(10.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile requires 0 <= q <= 100, got 120")]
+    fn percentile_out_of_range_panics() {
+        let _ = arr![1, 2, 3].percentile(120.0);
+    }
 }
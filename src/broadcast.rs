@@ -0,0 +1,389 @@
+use crate::errors::BroadcastError;
+use crate::{Array, Dimension, Ix, IxDyn, Order, Shape};
+
+/// Computes the shape that results from broadcasting two shapes against each other.
+///
+/// The shapes are aligned on their trailing dimensions. Two dimensions are compatible when
+/// they are equal or when one of them is 1, in which case it is stretched to match the other.
+/// Missing leading dimensions are treated as 1.
+pub fn broadcast_shapes(lhs: &[usize], rhs: &[usize]) -> Result<Vec<usize>, BroadcastError> {
+    let ndim = lhs.len().max(rhs.len());
+    let mut shape = vec![0; ndim];
+
+    for (i, dim) in shape.iter_mut().enumerate() {
+        let l = dim_from_end(lhs, ndim - i);
+        let r = dim_from_end(rhs, ndim - i);
+        *dim = match (l, r) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => {
+                return Err(BroadcastError::IncompatibleShapes {
+                    lhs: lhs.to_vec(),
+                    rhs: rhs.to_vec(),
+                })
+            }
+        };
+    }
+
+    Ok(shape)
+}
+
+/// Returns the `n`-th dimension counted from the end, or 1 if the shape has fewer dimensions.
+fn dim_from_end(dims: &[usize], n: usize) -> usize {
+    if n <= dims.len() {
+        dims[dims.len() - n]
+    } else {
+        1
+    }
+}
+
+/// Trait for dimension types that can be broadcast against dimensions of type `Rhs`.
+///
+/// The associated `Output` type is the dimension type of the broadcast result, which is the
+/// type with the higher number of dimensions.
+pub trait BroadcastShape<Rhs: Dimension>: Dimension {
+    /// The dimension type of the broadcast result.
+    type Output: Dimension;
+
+    /// Computes the dimensions that result from broadcasting `self` against `rhs`.
+    fn broadcast_shape(&self, rhs: &Rhs) -> Result<Self::Output, BroadcastError>;
+}
+
+impl<const N: usize> BroadcastShape<Ix<N>> for Ix<N> {
+    type Output = Ix<N>;
+
+    fn broadcast_shape(&self, rhs: &Ix<N>) -> Result<Ix<N>, BroadcastError> {
+        let shape = broadcast_shapes(self.dims(), rhs.dims())?;
+        let mut output = *self;
+        output.dims_mut().copy_from_slice(&shape);
+        Ok(output)
+    }
+}
+
+/// Implements `BroadcastShape` between two fixed dimension types of different rank in both directions.
+macro_rules! impl_broadcast_shape {
+    ($smaller:literal, $larger:literal) => {
+        impl BroadcastShape<Ix<$larger>> for Ix<$smaller> {
+            type Output = Ix<$larger>;
+
+            fn broadcast_shape(&self, rhs: &Ix<$larger>) -> Result<Ix<$larger>, BroadcastError> {
+                let shape = broadcast_shapes(self.dims(), rhs.dims())?;
+                let mut output = *rhs;
+                output.dims_mut().copy_from_slice(&shape);
+                Ok(output)
+            }
+        }
+
+        impl BroadcastShape<Ix<$smaller>> for Ix<$larger> {
+            type Output = Ix<$larger>;
+
+            fn broadcast_shape(&self, rhs: &Ix<$smaller>) -> Result<Ix<$larger>, BroadcastError> {
+                let shape = broadcast_shapes(self.dims(), rhs.dims())?;
+                let mut output = *self;
+                output.dims_mut().copy_from_slice(&shape);
+                Ok(output)
+            }
+        }
+    };
+}
+
+impl_broadcast_shape!(1, 2);
+impl_broadcast_shape!(1, 3);
+impl_broadcast_shape!(1, 4);
+impl_broadcast_shape!(2, 3);
+impl_broadcast_shape!(2, 4);
+impl_broadcast_shape!(3, 4);
+
+impl BroadcastShape<IxDyn> for IxDyn {
+    type Output = IxDyn;
+
+    fn broadcast_shape(&self, rhs: &IxDyn) -> Result<IxDyn, BroadcastError> {
+        Ok(IxDyn::new(&broadcast_shapes(self.dims(), rhs.dims())?))
+    }
+}
+
+impl<const N: usize> BroadcastShape<Ix<N>> for IxDyn {
+    type Output = IxDyn;
+
+    fn broadcast_shape(&self, rhs: &Ix<N>) -> Result<IxDyn, BroadcastError> {
+        Ok(IxDyn::new(&broadcast_shapes(self.dims(), rhs.dims())?))
+    }
+}
+
+impl<const N: usize> BroadcastShape<IxDyn> for Ix<N> {
+    type Output = IxDyn;
+
+    fn broadcast_shape(&self, rhs: &IxDyn) -> Result<IxDyn, BroadcastError> {
+        Ok(IxDyn::new(&broadcast_shapes(self.dims(), rhs.dims())?))
+    }
+}
+
+/// Computes the strides for reading an array of shape `dims` laid out with `strides` as if it had
+/// the broadcast shape `out_dims`. Stretched and missing dimensions get a stride of 0.
+pub(crate) fn broadcast_strides(
+    dims: &[usize],
+    strides: &[usize],
+    out_dims: &[usize],
+) -> Vec<usize> {
+    let mut out = vec![0; out_dims.len()];
+    let offset = out_dims.len() - dims.len();
+    for (i, (&dim, &stride)) in dims.iter().zip(strides).enumerate() {
+        if dim != 1 {
+            out[offset + i] = stride;
+        }
+    }
+    out
+}
+
+/// Visits every position of the shape `dims` in row-major order, calling `f` with the matching
+/// offsets into two buffers laid out with the given strides.
+pub(crate) fn for_each_offset_pair<F>(dims: &[usize], lhs: &[usize], rhs: &[usize], mut f: F)
+where
+    F: FnMut(usize, usize),
+{
+    let size: usize = dims.iter().product();
+    if size == 0 {
+        return;
+    }
+
+    let mut index = vec![0; dims.len()];
+    let (mut l, mut r) = (0, 0);
+    for _ in 0..size {
+        f(l, r);
+        for axis in (0..dims.len()).rev() {
+            index[axis] += 1;
+            l += lhs[axis];
+            r += rhs[axis];
+            if index[axis] < dims[axis] {
+                break;
+            }
+            l -= lhs[axis] * dims[axis];
+            r -= rhs[axis] * dims[axis];
+            index[axis] = 0;
+        }
+    }
+}
+
+/// Combines two arrays element-wise after broadcasting them to a common shape.
+pub(crate) fn zip_with<T, U, V, D, E, F>(
+    lhs: &Array<T, D>,
+    rhs: &Array<U, E>,
+    mut f: F,
+) -> Result<Array<V, D::Output>, BroadcastError>
+where
+    D: BroadcastShape<E>,
+    E: Dimension,
+    F: FnMut(&T, &U) -> V,
+{
+    let out_dim = lhs
+        .shape()
+        .raw_dim()
+        .broadcast_shape(rhs.shape().raw_dim())?;
+
+    // Operands with the same shape and layout are combined buffer to buffer, keeping the layout.
+    let same_layout = lhs.shape().dims() == rhs.shape().dims() && lhs.order() == rhs.order();
+    let order = if same_layout {
+        lhs.order()
+    } else {
+        Order::RowMajor
+    };
+    let data = if same_layout {
+        lhs.data()
+            .iter()
+            .zip(rhs.data().iter())
+            .map(|(a, b)| f(a, b))
+            .collect()
+    } else {
+        let out_dims = out_dim.dims();
+        let lhs_strides = broadcast_strides(lhs.shape().dims(), lhs.strides(), out_dims);
+        let rhs_strides = broadcast_strides(rhs.shape().dims(), rhs.strides(), out_dims);
+
+        let mut data = Vec::with_capacity(out_dim.size());
+        for_each_offset_pair(out_dims, &lhs_strides, &rhs_strides, |l, r| {
+            data.push(f(&lhs.data()[l], &rhs.data()[r]));
+        });
+        data
+    };
+
+    Ok(Array::from_parts_in_order(data, Shape::new(out_dim), order))
+}
+
+/// Updates `lhs` in place from the matching elements of `rhs`, which is broadcast to the shape
+/// of `lhs`. Fails if `rhs` cannot be broadcast to exactly that shape.
+pub(crate) fn zip_assign<T, U, D, E, F>(
+    lhs: &mut Array<T, D>,
+    rhs: &Array<U, E>,
+    mut f: F,
+) -> Result<(), BroadcastError>
+where
+    D: Dimension,
+    E: Dimension,
+    F: FnMut(&mut T, &U),
+{
+    let dims = lhs.shape().dims().to_vec();
+    if broadcast_shapes(&dims, rhs.shape().dims())? != dims {
+        return Err(BroadcastError::IncompatibleShapes {
+            lhs: dims,
+            rhs: rhs.shape().dims().to_vec(),
+        });
+    }
+
+    if lhs.shape().dims() == rhs.shape().dims() && lhs.order() == rhs.order() {
+        for (a, b) in lhs.as_mut_slice().iter_mut().zip(rhs.data()) {
+            f(a, b);
+        }
+    } else {
+        let lhs_strides = lhs.strides().to_vec();
+        let rhs_strides = broadcast_strides(rhs.shape().dims(), rhs.strides(), &dims);
+        let data = lhs.as_mut_slice();
+        for_each_offset_pair(&dims, &lhs_strides, &rhs_strides, |l, r| {
+            f(&mut data[l], &rhs.data()[r]);
+        });
+    }
+    Ok(())
+}
+
+impl<T: Clone, D: Dimension> Array<T, D> {
+    /// Expands the array to the shape `shape` by repeating it along axes of length 1 and along
+    /// missing leading axes, returning a new row-major array.
+    ///
+    /// Returns `BroadcastError::IncompatibleShapes` if the array cannot be broadcast to `shape`,
+    /// which is the case when broadcasting the two shapes together does not give `shape` itself.
+    pub fn broadcast_to<E: Dimension>(&self, shape: E) -> Result<Array<T, E>, BroadcastError> {
+        let dims = self.shape().dims();
+        if broadcast_shapes(dims, shape.dims())? != shape.dims() {
+            return Err(BroadcastError::IncompatibleShapes {
+                lhs: dims.to_vec(),
+                rhs: shape.dims().to_vec(),
+            });
+        }
+
+        let strides = broadcast_strides(dims, self.strides(), shape.dims());
+        let mut data = Vec::with_capacity(shape.size());
+        for_each_offset_pair(shape.dims(), &strides, &strides, |offset, _| {
+            data.push(self.data()[offset].clone());
+        });
+        Ok(Array::from_parts(data, Shape::new(shape)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::broadcast_shapes;
+    use crate::{BroadcastError, Ix, IxDyn, Order};
+
+    #[test]
+    fn broadcast_shapes_scalar_like() {
+        assert_eq!(broadcast_shapes(&[1], &[3, 4]).unwrap(), vec![3, 4]);
+        assert_eq!(broadcast_shapes(&[2, 3], &[1]).unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn broadcast_shapes_row_and_column_vectors() {
+        assert_eq!(broadcast_shapes(&[3, 1], &[2]).unwrap(), vec![3, 2]);
+        assert_eq!(broadcast_shapes(&[3, 1], &[1, 4]).unwrap(), vec![3, 4]);
+        assert_eq!(
+            broadcast_shapes(&[2, 1, 3], &[4, 1]).unwrap(),
+            vec![2, 4, 3]
+        );
+    }
+
+    #[test]
+    fn broadcast_shapes_incompatible() {
+        let result = broadcast_shapes(&[3], &[4]);
+        assert!(matches!(
+            result,
+            Err(BroadcastError::IncompatibleShapes { ref lhs, ref rhs }) if lhs == &[3] && rhs == &[4]
+        ));
+    }
+
+    #[test]
+    fn add_column_vector_to_row_vector() {
+        let a = arr![[1], [2], [3]];
+        let b = arr![10, 20];
+        let c = &a + &b;
+
+        assert_eq!(c.shape().dims(), &[3, 2]);
+        assert_eq!(c.data(), &vec![11, 21, 12, 22, 13, 23]);
+    }
+
+    #[test]
+    fn mul_matrix_by_row_vector() {
+        let a = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let b = arr![2.0, 0.5, 1.0];
+        let c = &b * &a;
+
+        assert_eq!(c.shape().dims(), &[2, 3]);
+        assert_eq!(c.data(), &vec![2.0, 1.0, 3.0, 8.0, 2.5, 6.0]);
+    }
+
+    #[test]
+    fn sub_scalar_like_array() {
+        let a = arr![[5, 6], [7, 8]];
+        let b = arr![1];
+        let c = &a - &b;
+
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data(), &vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn add_same_rank_stretched_dims() {
+        let a = arr![[1, 2, 3]];
+        let b = arr![[10], [20]];
+        let c = a + b;
+
+        assert_eq!(c.shape().dims(), &[2, 3]);
+        assert_eq!(c.data(), &vec![11, 12, 13, 21, 22, 23]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible shapes for broadcasting: [3] and [4]")]
+    fn add_incompatible_shapes() {
+        let _ = arr![1, 2, 3] + arr![1, 2, 3, 4];
+    }
+
+    #[test]
+    fn broadcast_to_3x1_to_3x4() {
+        let arr = arr![[1], [2], [3]];
+        let expanded = arr.broadcast_to(Ix::<2>::new([3, 4])).unwrap();
+
+        assert_eq!(expanded.shape().dims(), &[3, 4]);
+        assert_eq!(expanded.data(), &vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn broadcast_to_higher_rank_f64() {
+        let arr = arr![0.5, 1.5].to_order(Order::ColMajor);
+        let expanded = arr.broadcast_to(IxDyn::new(&[2, 1, 2])).unwrap();
+
+        assert_eq!(expanded.shape().dims(), &[2, 1, 2]);
+        assert_eq!(expanded.data(), &vec![0.5, 1.5, 0.5, 1.5]);
+
+        let col = arr![[1.0, 2.0], [3.0, 4.0]].to_order(Order::ColMajor);
+        let expanded = col.broadcast_to(Ix::<3>::new([2, 2, 2])).unwrap();
+        assert_eq!(
+            expanded.data(),
+            &vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn broadcast_to_incompatible() {
+        let err = arr![[1, 2, 3]]
+            .broadcast_to(Ix::<2>::new([2, 4]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shapes for broadcasting: [1, 3] and [2, 4]"
+        );
+
+        // The target shape itself cannot be stretched.
+        let err = arr![[1, 2], [3, 4]]
+            .broadcast_to(Ix::<2>::new([1, 2]))
+            .unwrap_err();
+        assert!(matches!(err, BroadcastError::IncompatibleShapes { .. }));
+        assert!(arr![1, 2].broadcast_to(Ix::<1>::new([2])).is_ok());
+    }
+}
@@ -0,0 +1,322 @@
+use std::cmp::Ordering;
+
+use crate::broadcast::{zip_with, BroadcastShape};
+use crate::errors::{BroadcastError, ShapeError};
+use crate::{Array, Dimension, Ix, Shape};
+
+/// Implements an element-wise comparison method that broadcasts both arrays to a common shape
+/// and returns a boolean array of the results.
+macro_rules! impl_comparison {
+    ($(#[$doc:meta])* $method:ident, $bound:ident, $op:tt) => {
+        $(#[$doc])*
+        pub fn $method<E>(
+            &self,
+            other: &Array<T, E>,
+        ) -> Result<Array<bool, D::Output>, BroadcastError>
+        where
+            T: $bound,
+            D: BroadcastShape<E>,
+            E: Dimension,
+        {
+            zip_with(self, other, |a, b| a $op b)
+        }
+    };
+}
+
+impl<T, D: Dimension> Array<T, D> {
+    impl_comparison!(
+        /// Compares the arrays element-wise for equality, broadcasting them to a common shape.
+        eq_elem, PartialEq, ==
+    );
+
+    impl_comparison!(
+        /// Compares the arrays element-wise for inequality, broadcasting them to a common shape.
+        ne_elem, PartialEq, !=
+    );
+
+    impl_comparison!(
+        /// Tests element-wise whether `self` is greater than `other`, broadcasting them to a
+        /// common shape.
+        gt, PartialOrd, >
+    );
+
+    impl_comparison!(
+        /// Tests element-wise whether `self` is less than `other`, broadcasting them to a common
+        /// shape.
+        lt, PartialOrd, <
+    );
+
+    impl_comparison!(
+        /// Tests element-wise whether `self` is greater than or equal to `other`, broadcasting
+        /// them to a common shape.
+        ge, PartialOrd, >=
+    );
+
+    impl_comparison!(
+        /// Tests element-wise whether `self` is less than or equal to `other`, broadcasting them
+        /// to a common shape.
+        le, PartialOrd, <=
+    );
+}
+
+impl<T: Clone, D: Dimension> Array<T, D> {
+    /// Returns a 1D array of the elements where `mask` is `true`, in row-major order.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the mask does not have the same shape as the
+    /// array.
+    pub fn masked_select(&self, mask: &Array<bool, D>) -> Result<Array<T, Ix<1>>, ShapeError> {
+        if mask.shape().dims() != self.shape().dims() {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "mask of shape {:?} does not match array of shape {:?}",
+                mask.shape().dims(),
+                self.shape().dims()
+            )));
+        }
+
+        let data: Vec<T> = self
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, &keep)| keep)
+            .map(|(x, _)| x.clone())
+            .collect();
+        let shape = Shape::new(Ix::<1>::new([data.len()]));
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Chooses elements from `a` where `cond` is `true` and from `b` where it is `false`, like
+    /// NumPy's `where`. The three arrays are broadcast to a common shape.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the shapes cannot be broadcast together.
+    pub fn where_(
+        cond: &Array<bool, D>,
+        a: &Array<T, D>,
+        b: &Array<T, D>,
+    ) -> Result<Array<T, D>, ShapeError>
+    where
+        D: BroadcastShape<D, Output = D>,
+    {
+        let incompatible = |_| {
+            ShapeError::IncompatibleShape(format!(
+                "cannot broadcast condition of shape {:?} with choices of shapes {:?} and {:?}",
+                cond.shape().dims(),
+                a.shape().dims(),
+                b.shape().dims()
+            ))
+        };
+        let chosen = zip_with(cond, a, |&c, x| (c, x.clone())).map_err(incompatible)?;
+        zip_with(
+            &chosen,
+            b,
+            |(c, x), y| if *c { x.clone() } else { y.clone() },
+        )
+        .map_err(incompatible)
+    }
+}
+
+impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
+    /// Returns the element-wise maximum of the arrays, broadcasting them to a common shape.
+    ///
+    /// Like NumPy's `maximum`, a `NaN` in either array propagates to the result rather than
+    /// being skipped.
+    ///
+    /// Returns `BroadcastError::IncompatibleShapes` if the shapes cannot be broadcast together.
+    pub fn maximum<E>(&self, other: &Array<T, E>) -> Result<Array<T, D::Output>, BroadcastError>
+    where
+        D: BroadcastShape<E>,
+        E: Dimension,
+    {
+        zip_with(self, other, |&a, &b| pick(a, b, Ordering::Greater))
+    }
+
+    /// Returns the element-wise minimum of the arrays, broadcasting them to a common shape.
+    ///
+    /// Like NumPy's `minimum`, a `NaN` in either array propagates to the result rather than
+    /// being skipped.
+    ///
+    /// Returns `BroadcastError::IncompatibleShapes` if the shapes cannot be broadcast together.
+    pub fn minimum<E>(&self, other: &Array<T, E>) -> Result<Array<T, D::Output>, BroadcastError>
+    where
+        D: BroadcastShape<E>,
+        E: Dimension,
+    {
+        zip_with(self, other, |&a, &b| pick(a, b, Ordering::Less))
+    }
+}
+
+/// Returns `b` if it compares as `wanted` against `a` and `a` otherwise, except that an
+/// unordered pair gives whichever of the two is not equal to itself, such as a `NaN`.
+pub(crate) fn pick<T: PartialOrd>(a: T, b: T, wanted: Ordering) -> T {
+    match b.partial_cmp(&a) {
+        Some(ordering) if ordering == wanted => b,
+        Some(_) => a,
+        None if b.partial_cmp(&b).is_none() => b,
+        None => a,
+    }
+}
+
+impl<D: Dimension> Array<bool, D> {
+    /// Returns `true` if any element is `true`, stopping at the first one. An empty array gives
+    /// `false`.
+    pub fn any(&self) -> bool {
+        self.data().iter().any(|&x| x)
+    }
+
+    /// Returns `true` if every element is `true`, stopping at the first `false`. An empty array
+    /// gives `true`.
+    pub fn all(&self) -> bool {
+        self.data().iter().all(|&x| x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, BroadcastError, Ix, Shape, ShapeError};
+
+    #[test]
+    fn compare_i64_1d() {
+        let lhs = arr![1, 2, 3];
+        let rhs = arr![0, 2, 5];
+
+        assert_eq!(lhs.gt(&rhs).unwrap().data(), &vec![true, false, false]);
+        assert_eq!(lhs.lt(&rhs).unwrap().data(), &vec![false, false, true]);
+        assert_eq!(lhs.ge(&rhs).unwrap().data(), &vec![true, true, false]);
+        assert_eq!(lhs.le(&rhs).unwrap().data(), &vec![false, true, true]);
+        assert_eq!(lhs.eq_elem(&rhs).unwrap().data(), &vec![false, true, false]);
+        assert_eq!(lhs.ne_elem(&rhs).unwrap().data(), &vec![true, false, true]);
+    }
+
+    #[test]
+    fn compare_f64_2d_broadcast() {
+        let matrix = arr![[0.5, 1.5], [2.5, -0.5]];
+        let row = arr![1.0, -1.0];
+        let result = matrix.gt(&row).unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 2]);
+        assert_eq!(result.data(), &vec![false, true, true, true]);
+    }
+
+    #[test]
+    fn compare_nan_is_never_equal() {
+        let arr = arr![f64::NAN, 1.0];
+
+        assert_eq!(arr.eq_elem(&arr).unwrap().data(), &vec![false, true]);
+        assert_eq!(arr.ge(&arr).unwrap().data(), &vec![false, true]);
+    }
+
+    #[test]
+    fn compare_incompatible_shapes() {
+        let err = arr![1, 2, 3].lt(&arr![1, 2]).unwrap_err();
+        assert!(matches!(err, BroadcastError::IncompatibleShapes { .. }));
+    }
+
+    #[test]
+    fn masked_select_i32_1d_positive() {
+        let a = arr![-1, 2, -3, 4];
+        let mask = a.gt(&zeros!(i32, 4)).unwrap();
+        let selected = a.masked_select(&mask).unwrap();
+
+        assert_eq!(selected.data(), &vec![2, 4]);
+        assert_eq!(selected.shape().dims(), &[2]);
+    }
+
+    #[test]
+    fn masked_select_f64_2d_row_major() {
+        let a = arr![[0.5, -1.5], [2.5, -3.5]];
+        let mask = a.lt(&arr![0.0]).unwrap();
+
+        assert_eq!(a.masked_select(&mask).unwrap().data(), &vec![-1.5, -3.5]);
+    }
+
+    #[test]
+    fn masked_select_shape_mismatch() {
+        let a = arr![[1, 2], [3, 4]];
+        let mask = arr![[true, false, true], [false, true, false]];
+
+        let err = a.masked_select(&mask).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+    }
+
+    #[test]
+    fn where_checkerboard_i64_2d() {
+        let cond = arr![[true, false, true], [false, true, false]];
+        let result = Array::where_(&cond, &ones!(i64, 2, 3), &zeros!(i64, 2, 3)).unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 3]);
+        assert_eq!(result.data(), &vec![1, 0, 1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn where_f64_broadcast() {
+        let a = arr![[0.5, -1.5], [2.5, -3.5]];
+        let cond = a.gt(&arr![[0.0]]).unwrap();
+        let result = Array::where_(&cond, &a, &arr![[0.0]]).unwrap();
+
+        assert_eq!(result.data(), &vec![0.5, 0.0, 2.5, 0.0]);
+    }
+
+    #[test]
+    fn where_incompatible_shapes() {
+        let cond = arr![true, false, true];
+        let err = Array::where_(&cond, &arr![1, 2], &arr![3, 4]).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+    }
+
+    #[test]
+    fn any_all_bool_2d() {
+        let mask = arr![[true, false], [true, true]];
+        assert!(mask.any());
+        assert!(!mask.all());
+
+        assert!(arr![[true, true]].all());
+        assert!(!arr![false, false, false].any());
+    }
+
+    #[test]
+    fn any_all_with_comparisons() {
+        let a = arr![[2.5, 3.5], [4.5, 5.5]];
+        let b = arr![1.0, 2.0];
+
+        assert!(a.gt(&b).unwrap().all());
+        assert!(!a.lt(&b).unwrap().any());
+        assert!(a.eq_elem(&arr![[2.5], [0.0]]).unwrap().any());
+    }
+
+    #[test]
+    fn any_all_empty() {
+        let empty = Array::<bool, Ix<2>>::new(vec![], Shape::new(Ix::<2>::new([0, 3]))).unwrap();
+
+        assert!(empty.all());
+        assert!(!empty.any());
+    }
+
+    #[test]
+    fn maximum_minimum_i64_1d() {
+        let lhs = arr![1, 5, 3];
+        let rhs = arr![4, 2, 6];
+
+        assert_eq!(lhs.maximum(&rhs).unwrap(), arr![4, 5, 6]);
+        assert_eq!(lhs.minimum(&rhs).unwrap(), arr![1, 2, 3]);
+    }
+
+    #[test]
+    fn maximum_minimum_f64_broadcast_and_nan() {
+        let lhs = arr![[1.0, f64::NAN], [-2.0, 0.5]];
+        let rhs = arr![0.0, 1.0];
+
+        let max = lhs.maximum(&rhs).unwrap();
+        assert_eq!(max.shape().dims(), &[2, 2]);
+        assert_eq!(max[[0, 0]], 1.0);
+        assert!(max[[0, 1]].is_nan());
+        assert_eq!(max.data()[2..], [0.0, 1.0]);
+
+        let min = rhs.minimum(&lhs).unwrap();
+        assert!(min[[0, 1]].is_nan());
+        assert_eq!(min.data()[2..], [-2.0, 0.5]);
+    }
+
+    #[test]
+    fn maximum_incompatible_shapes() {
+        let err = arr![1, 2, 3].maximum(&arr![1, 2]).unwrap_err();
+        assert!(matches!(err, BroadcastError::IncompatibleShapes { .. }));
+    }
+}
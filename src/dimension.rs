@@ -2,7 +2,7 @@
 ///
 /// This trait allows for different representations of dimensions while providing
 /// a common interface for querying array properties.
-pub trait Dimension {
+pub trait Dimension: Clone {
     /// Returns the number of dimensions.
     fn ndim(&self) -> usize;
 
@@ -11,4 +11,35 @@ pub trait Dimension {
 
     /// Returns a slice of the dimensions.
     fn dims(&self) -> &[usize];
+
+    /// Returns a mutable slice of the dimensions.
+    fn dims_mut(&mut self) -> &mut [usize];
+
+    /// Constructs the dimension from a slice of lengths, or returns `None` if the number of
+    /// lengths does not match the rank of the dimension type.
+    fn from_dims(dims: &[usize]) -> Option<Self>;
+}
+
+/// Trait for dimension types that can drop one of their axes.
+///
+/// This is used by operations that reduce an array along an axis, such as `sum_axis`,
+/// to describe the dimension type of the result.
+pub trait RemoveAxis: Dimension {
+    /// The dimension type with one axis fewer.
+    type Smaller: Dimension;
+
+    /// Returns the dimensions with the given axis removed.
+    fn remove_axis(&self, axis: usize) -> Self::Smaller;
+}
+
+/// Trait for dimension types that can gain an extra axis.
+///
+/// This is the counterpart of `RemoveAxis`, used by operations that add an axis to an array,
+/// such as building up the dimension of a slice specification in `s!`.
+pub trait InsertAxis: Dimension {
+    /// The dimension type with one axis more.
+    type Larger: Dimension;
+
+    /// Returns the dimensions with an axis of length `len` inserted before `axis`.
+    fn insert_axis(&self, axis: usize, len: usize) -> Self::Larger;
 }
@@ -0,0 +1,233 @@
+use std::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::errors::EinsumError;
+use crate::{Array, ArrayDyn, IxDyn, Shape};
+
+/// A parsed einsum specification: the index labels of each operand and of the output.
+struct Spec {
+    inputs: Vec<Vec<char>>,
+    output: Vec<char>,
+}
+
+impl Spec {
+    /// Parses an explicit specification such as `"ij,jk->ik"`, whose labels are ASCII letters.
+    fn parse(spec: &str) -> Result<Spec, EinsumError> {
+        let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+        let (inputs, output) = spec.split_once("->").ok_or_else(|| {
+            EinsumError::InvalidSpec(format!("{:?} has no explicit output after '->'", spec))
+        })?;
+
+        let labels = |term: &str| -> Result<Vec<char>, EinsumError> {
+            match term.chars().find(|c| !c.is_ascii_alphabetic()) {
+                Some(c) => Err(EinsumError::InvalidSpec(format!(
+                    "unsupported character {:?} in {:?}",
+                    c, spec
+                ))),
+                None => Ok(term.chars().collect()),
+            }
+        };
+        let inputs = inputs
+            .split(',')
+            .map(labels)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = labels(output)?;
+
+        for (i, &label) in output.iter().enumerate() {
+            if output[..i].contains(&label) {
+                return Err(EinsumError::InvalidSpec(format!(
+                    "output index '{}' is repeated in {:?}",
+                    label, spec
+                )));
+            }
+            if !inputs.iter().any(|input| input.contains(&label)) {
+                return Err(EinsumError::InvalidSpec(format!(
+                    "output index '{}' does not appear in any operand of {:?}",
+                    label, spec
+                )));
+            }
+        }
+
+        Ok(Spec { inputs, output })
+    }
+}
+
+impl<T: Zero + Mul<Output = T> + Copy> Array<T, IxDyn> {
+    /// Evaluates an Einstein summation over `operands` as described by `spec`, matching NumPy's
+    /// `einsum` in explicit mode.
+    ///
+    /// The specification lists the index labels of each operand, separated by commas, followed
+    /// by `->` and the labels of the output, for example `"ij,jk->ik"` for a matrix product,
+    /// `"ij->ji"` for a transpose, `"ii->"` for a trace or `"ij->i"` for a sum over rows. Every
+    /// label that does not appear in the output is summed over, and a label repeated within an
+    /// operand walks its diagonal.
+    ///
+    /// Returns `EinsumError::InvalidSpec` if the specification cannot be parsed or has no
+    /// explicit output, and the other `EinsumError` variants if it does not agree with the
+    /// number, ranks or lengths of the operands.
+    pub fn einsum(spec: &str, operands: &[&ArrayDyn<T>]) -> Result<ArrayDyn<T>, EinsumError> {
+        let spec = Spec::parse(spec)?;
+        if spec.inputs.len() != operands.len() {
+            return Err(EinsumError::OperandCountMismatch {
+                expected: spec.inputs.len(),
+                actual: operands.len(),
+            });
+        }
+
+        // Every distinct label in order of first appearance, together with its length.
+        let mut labels: Vec<(char, usize)> = Vec::new();
+        for (operand, (input, array)) in spec.inputs.iter().zip(operands).enumerate() {
+            if input.len() != array.ndim() {
+                return Err(EinsumError::RankMismatch {
+                    operand,
+                    expected: input.len(),
+                    actual: array.ndim(),
+                });
+            }
+            for (&label, &len) in input.iter().zip(array.shape().dims()) {
+                match labels.iter().find(|&&(l, _)| l == label) {
+                    Some(&(_, first)) if first != len => {
+                        return Err(EinsumError::LengthMismatch {
+                            label,
+                            first,
+                            second: len,
+                        });
+                    }
+                    Some(_) => {}
+                    None => labels.push((label, len)),
+                }
+            }
+        }
+        let position = |label: char| labels.iter().position(|&(l, _)| l == label).unwrap();
+
+        // For each operand and the output, the stride that one step along each label moves
+        // through its data. A label repeated within an operand adds up the strides of its axes.
+        let mut operand_strides = vec![vec![0; labels.len()]; operands.len()];
+        for ((strides, input), array) in operand_strides.iter_mut().zip(&spec.inputs).zip(operands)
+        {
+            for (&label, &stride) in input.iter().zip(array.strides()) {
+                strides[position(label)] += stride;
+            }
+        }
+        let out_dims: Vec<usize> = spec.output.iter().map(|&l| labels[position(l)].1).collect();
+        let mut out_strides = vec![0; labels.len()];
+        let mut stride = 1;
+        for (&label, &len) in spec.output.iter().zip(&out_dims).rev() {
+            out_strides[position(label)] = stride;
+            stride *= len;
+        }
+
+        let mut data = vec![T::zero(); out_dims.iter().product()];
+        let total: usize = labels.iter().map(|&(_, len)| len).product();
+        let mut index = vec![0; labels.len()];
+        for _ in 0..total {
+            let offset = |strides: &[usize]| -> usize {
+                index.iter().zip(strides).map(|(i, s)| i * s).sum()
+            };
+            let product = operands
+                .iter()
+                .zip(&operand_strides)
+                .map(|(array, strides)| array.data()[offset(strides)])
+                .reduce(|acc, x| acc * x);
+            if let Some(product) = product {
+                let out = &mut data[offset(&out_strides)];
+                *out = *out + product;
+            }
+
+            // Advance the index like an odometer, last label fastest.
+            for (i, value) in index.iter_mut().enumerate().rev() {
+                *value += 1;
+                if *value < labels[i].1 {
+                    break;
+                }
+                *value = 0;
+            }
+        }
+
+        Ok(Array::from_parts(data, Shape::new(IxDyn::new(&out_dims))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, EinsumError, Order};
+
+    #[test]
+    fn einsum_matmul() {
+        let a = arr![[1, 2, 3], [4, 5, 6]].into_dyn();
+        let b = arr![[1, 0], [0, 1], [2, -1]].into_dyn();
+        let product = Array::einsum("ij,jk->ik", &[&a, &b]).unwrap();
+
+        let expected = arr![[1, 2, 3], [4, 5, 6]]
+            .matmul(&arr![[1, 0], [0, 1], [2, -1]])
+            .unwrap();
+        assert_eq!(product, expected.into_dyn());
+    }
+
+    #[test]
+    fn einsum_transpose() {
+        let a = arr![[1, 2, 3], [4, 5, 6]].into_dyn();
+        let transposed = Array::einsum("ij->ji", &[&a]).unwrap();
+
+        assert_eq!(transposed, arr![[1, 4], [2, 5], [3, 6]].into_dyn());
+    }
+
+    #[test]
+    fn einsum_trace() {
+        let a = arr![[1, 2, 3], [4, 5, 6], [7, 8, 9]].into_dyn();
+        let trace = Array::einsum("ii->", &[&a]).unwrap();
+
+        assert_eq!(trace.ndim(), 0);
+        assert_eq!(trace.data(), &vec![15]);
+    }
+
+    #[test]
+    fn einsum_sum_over_axis() {
+        let a = arr![[1, 2, 3], [4, 5, 6]].into_dyn();
+
+        assert_eq!(
+            Array::einsum("ij->i", &[&a]).unwrap(),
+            arr![6, 15].into_dyn()
+        );
+        assert_eq!(
+            Array::einsum("ij->j", &[&a]).unwrap(),
+            arr![5, 7, 9].into_dyn()
+        );
+    }
+
+    #[test]
+    fn einsum_col_major_operand() {
+        let a = arr![[1, 2], [3, 4]].into_dyn().to_order(Order::ColMajor);
+        assert_eq!(
+            Array::einsum("ij->ji", &[&a]).unwrap(),
+            arr![[1, 3], [2, 4]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn einsum_rejects_unsupported_specs() {
+        let a = arr![[1, 2], [3, 4]].into_dyn();
+        let b = arr![[1, 2, 3]].into_dyn();
+
+        for spec in ["ij", "...i->i", "i1->i", "ij->ii", "ij->k"] {
+            let err = Array::einsum(spec, &[&a]).unwrap_err();
+            assert!(matches!(err, EinsumError::InvalidSpec(_)), "{}", spec);
+        }
+        assert!(matches!(
+            Array::einsum("ij,jk->ik", &[&a]).unwrap_err(),
+            EinsumError::OperandCountMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ));
+        assert!(matches!(
+            Array::einsum("i->i", &[&a]).unwrap_err(),
+            EinsumError::RankMismatch { operand: 0, .. }
+        ));
+        assert!(matches!(
+            Array::einsum("ij,jk->ik", &[&a, &b]).unwrap_err(),
+            EinsumError::LengthMismatch { label: 'j', .. }
+        ));
+    }
+}
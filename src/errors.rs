@@ -31,6 +31,89 @@ pub enum ArrayError {
     /// Signals that the operation requested for an array of a certain dimension is not implemented.
     #[error("Unimplemented dimension: {0}")]
     UnimplementedDimension(String),
+
+    /// Raised when an argument passed to an array constructor or operation is invalid,
+    /// such as a zero step for `arange`.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+/// Custom error types for shape operations.
+///
+/// This enum encapsulates the error conditions that can occur when the shapes of the arrays
+/// involved in an operation are incompatible with each other or with the requested result.
+#[derive(Debug, Error)]
+pub enum ShapeError {
+    /// Occurs when an array cannot take on or be combined with the requested shape.
+    #[error("Incompatible shape: {0}")]
+    IncompatibleShape(String),
+}
+
+/// Custom error types for broadcasting operations.
+///
+/// This enum encapsulates the error conditions that can occur when arrays of different shapes
+/// are combined and their shapes cannot be stretched to a common shape.
+#[derive(Debug, Error)]
+pub enum BroadcastError {
+    /// Occurs when two shapes have a dimension that differs and neither of them is 1.
+    #[error("Incompatible shapes for broadcasting: {lhs:?} and {rhs:?}")]
+    IncompatibleShapes { lhs: Vec<usize>, rhs: Vec<usize> },
+}
+
+/// Custom error types for linear algebra operations.
+///
+/// This enum encapsulates the error conditions that can occur when a matrix does not have the
+/// structure a decomposition or solve requires.
+#[derive(Debug, Error)]
+pub enum LinalgError {
+    /// Occurs when an operation that requires a square matrix is given a non-square one.
+    #[error("Matrix is not square: {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// Occurs when the right-hand side of a linear system does not have one entry per row of
+    /// the matrix.
+    #[error(
+        "Dimension mismatch: Expected a right-hand side of length {expected}, but got {actual}"
+    )]
+    DimensionMismatch { expected: usize, actual: usize },
+
+    /// Raised when a matrix is singular and therefore has no inverse or unique solution.
+    #[error("Matrix is singular")]
+    Singular,
+}
+
+/// Custom error types for einsum contractions.
+///
+/// This enum encapsulates the error conditions that can occur when an einsum specification cannot
+/// be parsed or does not agree with the operands it is applied to.
+#[derive(Debug, Error)]
+pub enum EinsumError {
+    /// Occurs when the specification is malformed or uses a form that is not supported, such as
+    /// an implicit output or an ellipsis.
+    #[error("Invalid einsum specification: {0}")]
+    InvalidSpec(String),
+
+    /// Raised when the specification names a different number of operands than were given.
+    #[error("Operand count mismatch: The specification names {expected} operands, but {actual} were given")]
+    OperandCountMismatch { expected: usize, actual: usize },
+
+    /// Raised when an operand does not have one dimension per index label.
+    #[error("Rank mismatch: Operand {operand} has {actual} dimensions, but the specification labels {expected}")]
+    RankMismatch {
+        operand: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// Occurs when an index label is used for dimensions of different lengths.
+    #[error(
+        "Length mismatch: Index '{label}' is used for dimensions of lengths {first} and {second}"
+    )]
+    LengthMismatch {
+        label: char,
+        first: usize,
+        second: usize,
+    },
 }
 
 /// Custom error types for visualization operations.
@@ -1,4 +1,6 @@
-use crate::Dimension;
+use std::ops::Index;
+
+use crate::{Dimension, InsertAxis, RemoveAxis};
 
 /// Fixed-size index type for multi-dimensional arrays.
 ///
@@ -33,4 +35,162 @@ impl<const N: usize> Dimension for Ix<N> {
     fn dims(&self) -> &[usize] {
         &self.dims
     }
+
+    /// Returns a mutable slice of the dimensions stored in this `Ix`.
+    fn dims_mut(&mut self) -> &mut [usize] {
+        &mut self.dims
+    }
+
+    /// Constructs an `Ix` from a slice of exactly `N` dimensions.
+    fn from_dims(dims: &[usize]) -> Option<Self> {
+        dims.try_into().ok().map(Ix::new)
+    }
+}
+
+impl<const N: usize> Index<usize> for Ix<N> {
+    type Output = usize;
+
+    /// Returns the length of the dimension at `axis`, panicking if `axis >= N`.
+    fn index(&self, axis: usize) -> &usize {
+        &self.dims[axis]
+    }
+}
+
+/// Implements `RemoveAxis` for a fixed dimension type, mapping `Ix<N>` to `Ix<N - 1>`.
+macro_rules! impl_remove_axis {
+    ($n:literal, $smaller:literal) => {
+        impl RemoveAxis for Ix<$n> {
+            type Smaller = Ix<$smaller>;
+
+            fn remove_axis(&self, axis: usize) -> Ix<$smaller> {
+                let mut dims = [0; $smaller];
+                let kept = self.dims.iter().enumerate().filter(|&(i, _)| i != axis);
+                for (dim, (_, &value)) in dims.iter_mut().zip(kept) {
+                    *dim = value;
+                }
+                Ix::new(dims)
+            }
+        }
+    };
+}
+
+impl_remove_axis!(1, 0);
+impl_remove_axis!(2, 1);
+impl_remove_axis!(3, 2);
+impl_remove_axis!(4, 3);
+impl_remove_axis!(5, 4);
+impl_remove_axis!(6, 5);
+
+/// Implements `InsertAxis` for a fixed dimension type, mapping `Ix<N>` to `Ix<N + 1>`.
+macro_rules! impl_insert_axis {
+    ($n:literal, $larger:literal) => {
+        impl InsertAxis for Ix<$n> {
+            type Larger = Ix<$larger>;
+
+            fn insert_axis(&self, axis: usize, len: usize) -> Ix<$larger> {
+                let mut dims = [len; $larger];
+                dims[..axis].copy_from_slice(&self.dims[..axis]);
+                dims[axis + 1..].copy_from_slice(&self.dims[axis..]);
+                Ix::new(dims)
+            }
+        }
+    };
+}
+
+impl_insert_axis!(0, 1);
+impl_insert_axis!(1, 2);
+impl_insert_axis!(2, 3);
+impl_insert_axis!(3, 4);
+impl_insert_axis!(4, 5);
+impl_insert_axis!(5, 6);
+
+/// Dynamic-rank index type for multi-dimensional arrays.
+///
+/// Unlike `Ix<N>`, the number of dimensions of an `IxDyn` is only known at runtime, which is
+/// useful when the rank of an array comes from data, such as when reading arrays from files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IxDyn {
+    dims: Vec<usize>,
+}
+
+impl IxDyn {
+    /// Creates a new `IxDyn` from a slice of dimensions of any length.
+    pub fn new(dims: &[usize]) -> Self {
+        IxDyn {
+            dims: dims.to_vec(),
+        }
+    }
+}
+
+impl Dimension for IxDyn {
+    /// Returns the number of dimensions represented by this `IxDyn`.
+    fn ndim(&self) -> usize {
+        self.dims.len()
+    }
+
+    /// Calculates the total number of elements in the array described by this `IxDyn`.
+    fn size(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Returns a slice of the dimensions stored in this `IxDyn`.
+    fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Returns a mutable slice of the dimensions stored in this `IxDyn`.
+    fn dims_mut(&mut self) -> &mut [usize] {
+        &mut self.dims
+    }
+
+    /// Constructs an `IxDyn` from a slice of any number of dimensions.
+    fn from_dims(dims: &[usize]) -> Option<Self> {
+        Some(IxDyn::new(dims))
+    }
+}
+
+impl Index<usize> for IxDyn {
+    type Output = usize;
+
+    /// Returns the length of the dimension at `axis`, panicking if it is out of bounds.
+    fn index(&self, axis: usize) -> &usize {
+        &self.dims[axis]
+    }
+}
+
+impl RemoveAxis for IxDyn {
+    type Smaller = IxDyn;
+
+    fn remove_axis(&self, axis: usize) -> IxDyn {
+        let mut dims = self.dims.clone();
+        dims.remove(axis);
+        IxDyn { dims }
+    }
+}
+
+impl InsertAxis for IxDyn {
+    type Larger = IxDyn;
+
+    fn insert_axis(&self, axis: usize, len: usize) -> IxDyn {
+        let mut dims = self.dims.clone();
+        dims.insert(axis, len);
+        IxDyn { dims }
+    }
+}
+
+impl<const N: usize> From<Ix<N>> for IxDyn {
+    /// Converts a fixed-rank index into a dynamic-rank one with the same dimensions.
+    fn from(ix: Ix<N>) -> IxDyn {
+        IxDyn::new(&ix.dims)
+    }
+}
+
+impl<const N: usize> TryFrom<IxDyn> for Ix<N> {
+    type Error = IxDyn;
+
+    /// Converts a dynamic-rank index into a fixed-rank one, returning the original index as
+    /// the error if it does not have exactly `N` dimensions.
+    fn try_from(ix: IxDyn) -> Result<Ix<N>, IxDyn> {
+        Ix::from_dims(&ix.dims).ok_or(ix)
+    }
 }
@@ -1,15 +1,41 @@
 #[macro_use]
 pub mod macros;
+pub mod arithmetic;
 pub mod array;
+pub mod broadcast;
+pub mod comparison;
 pub mod dimension;
+pub mod einsum;
 pub mod errors;
 pub mod ix;
+pub mod linalg;
+pub mod manipulation;
+pub mod math;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
 pub mod operations;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serde_impl;
 pub mod shape;
+pub mod signal;
+mod simd;
+pub mod sorting;
+pub mod view;
 pub mod visualization;
 
-pub use array::Array;
-pub use dimension::Dimension;
-pub use errors::ArrayError;
-pub use ix::Ix;
-pub use shape::Shape;
+// Re-exported so that macros can refer to `num_traits` from downstream crates.
+#[doc(hidden)]
+pub use num_traits;
+
+pub use array::{Array, ArrayDyn};
+pub use dimension::{Dimension, InsertAxis, RemoveAxis};
+pub use errors::{ArrayError, BroadcastError, EinsumError, LinalgError, ShapeError};
+pub use ix::{Ix, IxDyn};
+pub use linalg::NormOrd;
+pub use shape::{Order, Shape};
+pub use signal::ConvMode;
+pub use view::ArrayView;
@@ -0,0 +1,825 @@
+use std::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::errors::{LinalgError, ShapeError};
+use crate::{Array, Ix, Shape};
+
+/// Which vector norm `Array::norm` computes, matching the `ord` values of NumPy's
+/// `linalg.norm` for vectors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NormOrd {
+    /// The sum of the absolute values of the elements, `sum(|x|)`.
+    L1,
+    /// The Euclidean length of the vector, `sqrt(sum(x^2))`.
+    #[default]
+    L2,
+    /// The largest absolute value of the elements, `max(|x|)`.
+    Inf,
+}
+
+impl<T: Zero + Mul<Output = T> + Copy> Array<T, Ix<2>> {
+    /// Computes the matrix product of an `m x k` array with a `k x n` array, producing an
+    /// `m x n` array.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the number of columns of `self` does not match
+    /// the number of rows of `other`.
+    pub fn matmul(&self, other: &Array<T, Ix<2>>) -> Result<Array<T, Ix<2>>, ShapeError> {
+        let (m, k) = (self.shape().dims()[0], self.shape().dims()[1]);
+        let (rows, n) = (other.shape().dims()[0], other.shape().dims()[1]);
+        if k != rows {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot multiply matrices of shapes {:?} and {:?}",
+                self.shape().dims(),
+                other.shape().dims()
+            )));
+        }
+
+        let mut data = vec![T::zero(); m * n];
+        matmul_into(
+            &self.row_major_data(),
+            &other.row_major_data(),
+            &mut data,
+            m,
+            k,
+            n,
+        );
+
+        Ok(Array::from_parts(data, Shape::new(Ix::<2>::new([m, n]))))
+    }
+}
+
+/// Accumulates the product of the row-major `m x k` matrix `lhs` and `k x n` matrix `rhs` into
+/// the row-major `m x n` matrix `out`.
+fn matmul_into<T>(lhs: &[T], rhs: &[T], out: &mut [T], m: usize, k: usize, n: usize)
+where
+    T: Zero + Mul<Output = T> + Copy,
+{
+    // Iterating `j` innermost walks both the output row and the row of `rhs` contiguously,
+    // rather than striding down a column of `rhs` for every output element.
+    for i in 0..m {
+        let out_row = &mut out[i * n..(i + 1) * n];
+        for p in 0..k {
+            let a = lhs[i * k + p];
+            let rhs_row = &rhs[p * n..(p + 1) * n];
+            for (out, &b) in out_row.iter_mut().zip(rhs_row) {
+                *out = *out + a * b;
+            }
+        }
+    }
+}
+
+impl<T: Zero + Mul<Output = T> + Copy> Array<T, Ix<3>> {
+    /// Multiplies two stacks of matrices pairwise: a `batch x m x k` array with a
+    /// `batch x k x n` array, producing a `batch x m x n` array whose slice `b` is
+    /// `self[b].matmul(&other[b])`.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the batch sizes differ or if the matrices in
+    /// each pair cannot be multiplied.
+    pub fn batched_matmul(&self, other: &Array<T, Ix<3>>) -> Result<Array<T, Ix<3>>, ShapeError> {
+        let [batch, m, k] = [0, 1, 2].map(|axis| self.shape().dims()[axis]);
+        let [other_batch, rows, n] = [0, 1, 2].map(|axis| other.shape().dims()[axis]);
+        if batch != other_batch || k != rows {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot batch multiply matrices of shapes {:?} and {:?}",
+                self.shape().dims(),
+                other.shape().dims()
+            )));
+        }
+
+        let (lhs, rhs): (&[T], &[T]) = (&self.row_major_data(), &other.row_major_data());
+        let mut data = vec![T::zero(); batch * m * n];
+        for b in 0..batch {
+            matmul_into(
+                &lhs[b * m * k..(b + 1) * m * k],
+                &rhs[b * k * n..(b + 1) * k * n],
+                &mut data[b * m * n..(b + 1) * m * n],
+                m,
+                k,
+                n,
+            );
+        }
+
+        Ok(Array::from_parts(
+            data,
+            Shape::new(Ix::<3>::new([batch, m, n])),
+        ))
+    }
+}
+
+impl<T: Zero + Mul<Output = T> + Copy> Array<T, Ix<1>> {
+    /// Computes the inner product of two 1D arrays, the sum of their element-wise products.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the arrays have different lengths.
+    pub fn dot(&self, other: &Array<T, Ix<1>>) -> Result<T, ShapeError> {
+        if self.data().len() != other.data().len() {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot compute the dot product of vectors of lengths {} and {}",
+                self.data().len(),
+                other.data().len()
+            )));
+        }
+
+        Ok(self
+            .data()
+            .iter()
+            .zip(other.data())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Array<T, Ix<2>> {
+    /// Computes the Kronecker product of an `m1 x n1` matrix with an `m2 x n2` matrix: the
+    /// `(m1 * m2) x (n1 * n2)` block matrix whose block `(i, j)` is `self[[i, j]] * other`.
+    pub fn kron(&self, other: &Array<T, Ix<2>>) -> Array<T, Ix<2>> {
+        let (m1, n1) = (self.shape().dims()[0], self.shape().dims()[1]);
+        let (m2, n2) = (other.shape().dims()[0], other.shape().dims()[1]);
+        let (lhs, rhs): (&[T], &[T]) = (&self.row_major_data(), &other.row_major_data());
+
+        let mut data = Vec::with_capacity(m1 * m2 * n1 * n2);
+        for i in 0..m1 {
+            for k in 0..m2 {
+                for &a in &lhs[i * n1..(i + 1) * n1] {
+                    data.extend(rhs[k * n2..(k + 1) * n2].iter().map(|&b| a * b));
+                }
+            }
+        }
+        Array::from_parts(data, Shape::new(Ix::<2>::new([m1 * m2, n1 * n2])))
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Array<T, Ix<1>> {
+    /// Computes the outer product of two 1D arrays of lengths `m` and `n`, the `m x n` matrix
+    /// with `out[[i, j]] = self[i] * other[j]`.
+    pub fn outer(&self, other: &Array<T, Ix<1>>) -> Array<T, Ix<2>> {
+        let (m, n) = (self.len(), other.len());
+        let data = self
+            .iter()
+            .flat_map(|&a| other.iter().map(move |&b| a * b))
+            .collect();
+        Array::from_parts(data, Shape::new(Ix::<2>::new([m, n])))
+    }
+}
+
+impl<T: Clone> Array<T, Ix<2>> {
+    /// Returns the elements of the main diagonal, `a[[i, i]]` for `i` in `0..min(rows, cols)`.
+    pub fn diagonal(&self) -> Array<T, Ix<1>> {
+        self.diagonal_offset(0)
+    }
+
+    /// Returns the elements of the diagonal `offset` places above the main diagonal, or below
+    /// it for a negative offset. An offset beyond the edge of the matrix gives an empty array.
+    pub fn diagonal_offset(&self, offset: isize) -> Array<T, Ix<1>> {
+        let (rows, cols) = (self.shape().dims()[0], self.shape().dims()[1]);
+        let (row, col) = if offset >= 0 {
+            (0, offset.unsigned_abs())
+        } else {
+            (offset.unsigned_abs(), 0)
+        };
+        let len = rows.saturating_sub(row).min(cols.saturating_sub(col));
+
+        let data: Vec<T> = (0..len).map(|i| self[[row + i, col + i]].clone()).collect();
+        Array::from_parts(data, Shape::new(Ix::<1>::new([len])))
+    }
+}
+
+impl<T: Zero + Copy> Array<T, Ix<2>> {
+    /// Returns the sum of the elements on the main diagonal.
+    pub fn trace(&self) -> T {
+        self.diagonal().sum()
+    }
+}
+
+impl<T: Into<f64> + Copy> Array<T, Ix<1>> {
+    /// Computes the vector norm selected by `ord`. Every norm of an empty vector is `0.0`, and
+    /// every norm of a vector containing `NaN` is `NaN`.
+    pub fn norm(&self, ord: NormOrd) -> f64 {
+        let abs = self.iter().map(|&x| x.into().abs());
+        match ord {
+            NormOrd::L1 => abs.sum(),
+            NormOrd::L2 => abs.map(|x| x * x).sum::<f64>().sqrt(),
+            // Unlike `f64::max`, this keeps a `NaN` once seen, matching NumPy.
+            NormOrd::Inf => abs.fold(0.0, |max, x| if x.is_nan() || x > max { x } else { max }),
+        }
+    }
+}
+
+/// The LU decomposition of a square matrix with partial pivoting, storing `L` (with an implicit
+/// unit diagonal) below the diagonal and `U` on and above it.
+struct Lu {
+    n: usize,
+    lu: Vec<f64>,
+    /// `perm[i]` is the row of the original matrix that ended up in row `i`.
+    perm: Vec<usize>,
+    /// `-1.0` if an odd number of row swaps were made, `1.0` otherwise.
+    sign: f64,
+}
+
+impl Lu {
+    /// Decomposes the row-major `n x n` matrix in `lu`, returning `None` if a pivot is no larger
+    /// than `rtol` times the largest magnitude in its column of the input. An `rtol` of `0.0`
+    /// rejects only exactly zero pivots.
+    fn new(mut lu: Vec<f64>, n: usize, rtol: f64) -> Option<Lu> {
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        // Row swaps and eliminations never move entries between columns, so the tolerance for
+        // each pivot can be fixed from the input up front.
+        let tolerance: Vec<f64> = (0..n)
+            .map(|col| rtol * (0..n).fold(0.0, |max: f64, row| lu[row * n + col].abs().max(max)))
+            .collect();
+
+        for col in 0..n {
+            // Pivoting on the largest remaining entry in the column keeps the elimination
+            // numerically stable.
+            let pivot = (col..n)
+                .max_by(|&a, &b| lu[a * n + col].abs().total_cmp(&lu[b * n + col].abs()))
+                .unwrap_or(col);
+            if lu[pivot * n + col].abs() <= tolerance[col] {
+                return None;
+            }
+            if pivot != col {
+                for j in 0..n {
+                    lu.swap(pivot * n + j, col * n + j);
+                }
+                perm.swap(pivot, col);
+                sign = -sign;
+            }
+
+            let diag = lu[col * n + col];
+            for row in col + 1..n {
+                let factor = lu[row * n + col] / diag;
+                lu[row * n + col] = factor;
+                for j in col + 1..n {
+                    lu[row * n + j] -= factor * lu[col * n + j];
+                }
+            }
+        }
+
+        Some(Lu { n, lu, perm, sign })
+    }
+
+    fn det(&self) -> f64 {
+        (0..self.n).fold(self.sign, |det, i| det * self.lu[i * self.n + i])
+    }
+
+    /// Solves `A x = b` by forward and back substitution, returning `x`.
+    fn solve(&self, b: impl Fn(usize) -> f64) -> Vec<f64> {
+        let n = self.n;
+        let mut x: Vec<f64> = self.perm.iter().map(|&row| b(row)).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+            x[i] /= self.lu[i * n + i];
+        }
+        x
+    }
+}
+
+impl<T: Into<f64> + Copy> Array<T, Ix<2>> {
+    /// Computes the determinant of a square matrix by LU decomposition with partial pivoting.
+    ///
+    /// The determinant of a `0 x 0` matrix is `1.0`, and that of a matrix with an exactly zero
+    /// pivot is `0.0`. Returns `ShapeError::IncompatibleShape` if the matrix is not
+    /// square.
+    pub fn det(&self) -> Result<f64, ShapeError> {
+        let n = self.square_dim().map_err(|_| {
+            ShapeError::IncompatibleShape(format!(
+                "cannot compute the determinant of a non-square matrix of shape {:?}",
+                self.shape().dims()
+            ))
+        })?;
+        Ok(Lu::new(self.to_f64_vec(), n, 0.0).map_or(0.0, |lu| lu.det()))
+    }
+
+    /// Computes the inverse of a square matrix by LU decomposition with partial pivoting,
+    /// solving for one column of the inverse at a time.
+    ///
+    /// Returns `LinalgError::NotSquare` if the matrix is not square and `LinalgError::Singular`
+    /// if it is singular to working precision: if a pivot is no larger than
+    /// `n * f64::EPSILON` times the largest magnitude in its column.
+    pub fn inv(&self) -> Result<Array<f64, Ix<2>>, LinalgError> {
+        let n = self.square_dim()?;
+        let lu =
+            Lu::new(self.to_f64_vec(), n, n as f64 * f64::EPSILON).ok_or(LinalgError::Singular)?;
+
+        let mut data = vec![0.0; n * n];
+        for col in 0..n {
+            for (row, value) in lu
+                .solve(|row| if row == col { 1.0 } else { 0.0 })
+                .into_iter()
+                .enumerate()
+            {
+                data[row * n + col] = value;
+            }
+        }
+        Ok(Array::from_parts(data, Shape::new(Ix::<2>::new([n, n]))))
+    }
+
+    /// Solves the linear system `A x = b` for `x` by LU decomposition with partial pivoting.
+    ///
+    /// Returns `LinalgError::NotSquare` if the matrix is not square,
+    /// `LinalgError::DimensionMismatch` if `b` does not have one entry per row, and
+    /// `LinalgError::Singular` if the matrix is singular to working precision, as for
+    /// [`Array::inv`].
+    pub fn solve(&self, b: &Array<f64, Ix<1>>) -> Result<Array<f64, Ix<1>>, LinalgError> {
+        let n = self.square_dim()?;
+        if b.len() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: n,
+                actual: b.len(),
+            });
+        }
+        let lu =
+            Lu::new(self.to_f64_vec(), n, n as f64 * f64::EPSILON).ok_or(LinalgError::Singular)?;
+
+        let rhs = b.as_slice();
+        let x = lu.solve(|row| rhs[row]);
+        Ok(Array::from_parts(x, Shape::new(Ix::<1>::new([n]))))
+    }
+
+    /// Computes the reduced QR decomposition of an `m x n` matrix by Householder reflections.
+    ///
+    /// With `k = min(m, n)`, returns an `m x k` matrix `Q` with orthonormal columns and a
+    /// `k x n` upper-triangular matrix `R` such that `Q.matmul(&R)` is the input. This holds for
+    /// tall, square and wide matrices alike, so no error is currently returned; the `Result`
+    /// matches the other decompositions.
+    #[allow(clippy::type_complexity)]
+    pub fn qr(&self) -> Result<(Array<f64, Ix<2>>, Array<f64, Ix<2>>), LinalgError> {
+        let (m, n) = (self.shape().dims()[0], self.shape().dims()[1]);
+        let k = m.min(n);
+        let mut r = self.to_f64_vec();
+        let mut q = vec![0.0; m * m];
+        for i in 0..m {
+            q[i * m + i] = 1.0;
+        }
+
+        for j in 0..k {
+            // Choosing the sign of `alpha` opposite to the leading entry avoids cancellation
+            // when forming the reflection vector.
+            let norm = (j..m).map(|i| r[i * n + j].powi(2)).sum::<f64>().sqrt();
+            let alpha = if r[j * n + j] >= 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (j..m).map(|i| r[i * n + j]).collect();
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if v_norm == 0.0 {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= v_norm);
+
+            // Apply H = I - 2 v v^T to the remaining rows of R from the left...
+            for col in j..n {
+                let dot: f64 = v.iter().zip(j..m).map(|(vi, i)| vi * r[i * n + col]).sum();
+                for (vi, i) in v.iter().zip(j..m) {
+                    r[i * n + col] -= 2.0 * dot * vi;
+                }
+            }
+            // ...and accumulate Q = Q H from the right.
+            for row in 0..m {
+                let dot: f64 = v.iter().zip(j..m).map(|(vi, i)| q[row * m + i] * vi).sum();
+                for (vi, i) in v.iter().zip(j..m) {
+                    q[row * m + i] -= 2.0 * dot * vi;
+                }
+            }
+        }
+
+        let q: Vec<f64> = (0..m)
+            .flat_map(|row| q[row * m..row * m + k].to_vec())
+            .collect();
+        let r: Vec<f64> = (0..k)
+            .flat_map(|row| (0..n).map(move |col| (row, col)))
+            .map(|(row, col)| if col < row { 0.0 } else { r[row * n + col] })
+            .collect();
+        Ok((
+            Array::from_parts(q, Shape::new(Ix::<2>::new([m, k]))),
+            Array::from_parts(r, Shape::new(Ix::<2>::new([k, n]))),
+        ))
+    }
+
+    /// Computes the Frobenius norm of the matrix, the square root of the sum of the squares of
+    /// its elements.
+    pub fn frobenius_norm(&self) -> f64 {
+        self.to_f64_vec().iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    /// Returns the side length of a square matrix.
+    fn square_dim(&self) -> Result<usize, LinalgError> {
+        let (rows, cols) = (self.shape().dims()[0], self.shape().dims()[1]);
+        if rows != cols {
+            return Err(LinalgError::NotSquare { rows, cols });
+        }
+        Ok(rows)
+    }
+
+    fn to_f64_vec(&self) -> Vec<f64> {
+        self.iter().map(|&x| x.into()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NormOrd;
+    use crate::{Array, Ix, LinalgError, Order, Shape, ShapeError};
+
+    #[test]
+    fn matmul_i64_identity() {
+        let arr = arr![[1, 2], [3, 4]];
+        let product = arr.matmul(&Array::<i32, Ix<2>>::eye(2)).unwrap();
+
+        assert_eq!(product.shape().dims(), &[2, 2]);
+        assert_eq!(product.data(), &vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn matmul_i64_non_square() {
+        let lhs = arr![[1, 2], [3, 4]];
+        let rhs = arr![[5, 6, 7], [8, 9, 10]];
+        let product = lhs.matmul(&rhs).unwrap();
+
+        assert_eq!(product.shape().dims(), &[2, 3]);
+        assert_eq!(product.data(), &vec![21, 24, 27, 47, 54, 61]);
+    }
+
+    #[test]
+    fn matmul_f64_row_by_column() {
+        let lhs = arr![[0.5, 1.5, -2.0]];
+        let rhs = arr![[2.0], [4.0], [1.0]];
+        let product = lhs.matmul(&rhs).unwrap();
+
+        assert_eq!(product.shape().dims(), &[1, 1]);
+        assert_eq!(product.data(), &vec![5.0]);
+    }
+
+    #[test]
+    fn matmul_shape_mismatch() {
+        let lhs = arr![[1, 2, 3], [4, 5, 6]];
+        let rhs = arr![[1, 2], [3, 4]];
+
+        let err = lhs.matmul(&rhs).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: cannot multiply matrices of shapes [2, 3] and [2, 2]"
+        );
+    }
+
+    #[test]
+    fn dot_i64_1d() {
+        assert_eq!(arr![1, 2, 3].dot(&arr![4, 5, 6]).unwrap(), 32);
+    }
+
+    #[test]
+    fn dot_f64_1d() {
+        let lhs = arr![0.5, -1.5, 2.0];
+        let rhs = arr![4.0, 2.0, 0.25];
+
+        assert_eq!(lhs.dot(&rhs).unwrap(), -0.5);
+    }
+
+    #[test]
+    fn dot_length_mismatch() {
+        let err = arr![1, 2, 3].dot(&arr![1, 2]).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+    }
+
+    #[test]
+    fn diagonal_i64_square_and_non_square() {
+        let square = arr![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(square.diagonal().data(), &vec![1, 5, 9]);
+
+        let wide = arr![[1, 2, 3], [4, 5, 6]];
+        let diagonal = wide.diagonal();
+        assert_eq!(diagonal.shape().dims(), &[2]);
+        assert_eq!(diagonal.data(), &vec![1, 5]);
+    }
+
+    #[test]
+    fn diagonal_and_matmul_col_major() {
+        let row = arr![[1, 2, 3], [4, 5, 6]];
+        let col = row.to_order(Order::ColMajor);
+        let rhs = arr![[1, 0], [2, 1], [0, 3]].to_order(Order::ColMajor);
+
+        assert_eq!(col.diagonal().data(), &vec![1, 5]);
+        assert_eq!(col.diagonal_offset(1).data(), &vec![2, 6]);
+        assert_eq!(col.matmul(&rhs).unwrap(), row.matmul(&rhs).unwrap());
+        assert_eq!(col.matmul(&rhs).unwrap(), arr![[5, 11], [14, 23]]);
+    }
+
+    #[test]
+    fn diagonal_offset_f64() {
+        let arr = arr![
+            [0.5, 1.5, 2.5],
+            [3.5, 4.5, 5.5],
+            [6.5, 7.5, 8.5],
+            [9.5, 10.5, 11.5]
+        ];
+
+        assert_eq!(arr.diagonal_offset(1).data(), &vec![1.5, 5.5]);
+        assert_eq!(arr.diagonal_offset(2).data(), &vec![2.5]);
+        assert_eq!(arr.diagonal_offset(-1).data(), &vec![3.5, 7.5, 11.5]);
+        assert_eq!(arr.diagonal_offset(-3).data(), &vec![9.5]);
+        assert_eq!(arr.diagonal_offset(3).shape().dims(), &[0]);
+        assert_eq!(arr.diagonal_offset(-5).shape().dims(), &[0]);
+    }
+
+    #[test]
+    fn trace_i64_2d() {
+        assert_eq!(arr![[1, 2], [3, 4]].trace(), 5);
+        assert_eq!(arr![[1, 2, 3], [4, 5, 6]].trace(), 6);
+    }
+
+    #[test]
+    fn trace_f64_identity() {
+        assert_eq!(Array::<f64, Ix<2>>::eye(4).trace(), 4.0);
+        assert_eq!(Array::<f64, Ix<2>>::eye(0).trace(), 0.0);
+    }
+
+    #[test]
+    fn outer_i64_1d() {
+        let product = arr![1, 2].outer(&arr![3, 4, 5]);
+
+        assert_eq!(product.shape().dims(), &[2, 3]);
+        assert_eq!(product, arr![[3, 4, 5], [6, 8, 10]]);
+    }
+
+    #[test]
+    fn outer_f64_and_empty() {
+        let product = arr![0.5, -2.0].outer(&arr![4.0]);
+        assert_eq!(product.shape().dims(), &[2, 1]);
+        assert_eq!(product.data(), &vec![2.0, -8.0]);
+
+        let empty: Array<f64, Ix<1>> = std::iter::empty().collect();
+        assert_eq!(arr![1.0, 2.0].outer(&empty).shape().dims(), &[2, 0]);
+    }
+
+    #[test]
+    fn kron_identity_block_diagonal() {
+        let identity = Array::<i64, Ix<2>>::eye(2);
+        let block = arr![[1, 2], [3, 4]];
+        let product = identity.kron(&block);
+
+        assert_eq!(product.shape().dims(), &[4, 4]);
+        assert_eq!(
+            product,
+            arr![[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn kron_f64_non_square() {
+        let lhs = arr![[1.0, -2.0]];
+        let rhs = arr![[0.5], [1.5]];
+        let product = lhs.kron(&rhs);
+
+        assert_eq!(product.shape().dims(), &[2, 2]);
+        assert_eq!(product.data(), &vec![0.5, -1.0, 1.5, -3.0]);
+    }
+
+    #[test]
+    fn kron_col_major() {
+        let lhs = arr![[1, 2, 3], [4, 5, 6]].to_order(Order::ColMajor);
+        let rhs = arr![[1, 0], [0, -1]].to_order(Order::ColMajor);
+
+        assert_eq!(lhs.kron(&arr![[1]]), arr![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            lhs.kron(&rhs),
+            arr![[1, 2, 3], [4, 5, 6]].kron(&arr![[1, 0], [0, -1]])
+        );
+    }
+
+    #[test]
+    fn det_2x2_and_3x3() {
+        assert!((arr![[1.0, 2.0], [3.0, 4.0]].det().unwrap() + 2.0).abs() < 1e-12);
+
+        let arr = arr![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+        assert!((arr.det().unwrap() - 49.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn det_singular_and_pivoting() {
+        assert_eq!(arr![[1.0, 2.0], [2.0, 4.0]].det().unwrap(), 0.0);
+
+        // A zero in the leading position forces a row swap, which flips the sign.
+        let arr = arr![[0.0, 1.0], [1.0, 0.0]];
+        assert!((arr.det().unwrap() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn det_rejects_non_square() {
+        let err = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]].det().unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+    }
+
+    #[test]
+    fn inv_times_matrix_is_identity() {
+        let arr = arr![[4.0, 7.0, 2.0], [3.0, 6.0, 1.0], [2.0, 5.0, 3.0]];
+        let product = arr.matmul(&arr.inv().unwrap()).unwrap();
+        let identity = Array::<f64, Ix<2>>::eye(3);
+
+        for (&actual, &expected) in product.iter().zip(identity.iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn inv_2x2_known_values() {
+        let inverse = arr![[1.0, 2.0], [3.0, 4.0]].inv().unwrap();
+        let expected = [-2.0, 1.0, 1.5, -0.5];
+
+        for (&actual, &expected) in inverse.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn inv_rejects_singular_and_non_square() {
+        let err = arr![[1.0, 2.0], [2.0, 4.0]].inv().unwrap_err();
+        assert!(matches!(err, LinalgError::Singular));
+
+        let err = arr![[1.0, 2.0, 3.0]].inv().unwrap_err();
+        assert!(matches!(err, LinalgError::NotSquare { rows: 1, cols: 3 }));
+    }
+
+    #[test]
+    fn rank_deficient_3x3_is_singular() {
+        // The rows are in arithmetic progression, so elimination leaves a pivot of rounding
+        // error rather than an exact zero.
+        let arr = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+        assert!(matches!(arr.inv().unwrap_err(), LinalgError::Singular));
+        assert!(matches!(
+            arr.solve(&arr![1.0, 2.0, 3.0]).unwrap_err(),
+            LinalgError::Singular
+        ));
+        assert!(arr.det().unwrap().abs() < 1e-12);
+    }
+
+    #[test]
+    fn badly_scaled_diagonal_is_not_singular() {
+        assert_eq!(arr![[1.0, 0.0], [0.0, 1e-16]].det().unwrap(), 1e-16);
+
+        let arr = arr![[1e-20, 0.0], [0.0, 1e20]];
+        assert_eq!(arr.det().unwrap(), 1.0);
+        assert_eq!(arr.inv().unwrap(), arr![[1e20, 0.0], [0.0, 1e-20]]);
+        assert_eq!(arr.solve(&arr![1e-20, 1e20]).unwrap(), arr![1.0, 1.0]);
+    }
+
+    #[test]
+    fn solve_known_system() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3 has the solution (2, 3, -1).
+        let a = arr![[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+        let b = arr![8.0, -11.0, -3.0];
+        let x = a.solve(&b).unwrap();
+
+        for (&actual, &expected) in x.iter().zip([2.0, 3.0, -1.0].iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+        for i in 0..3 {
+            let residual: f64 = (0..3).map(|j| a[[i, j]] * x[[j]]).sum::<f64>() - b[[i]];
+            assert!(residual.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn solve_rejects_mismatch_and_singular() {
+        let err = arr![[1.0, 0.0], [0.0, 1.0]]
+            .solve(&arr![1.0, 2.0, 3.0])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            LinalgError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            }
+        ));
+
+        let err = arr![[1.0, 2.0], [2.0, 4.0]]
+            .solve(&arr![1.0, 2.0])
+            .unwrap_err();
+        assert!(matches!(err, LinalgError::Singular));
+
+        let err = arr![[1.0, 2.0]].solve(&arr![1.0]).unwrap_err();
+        assert!(matches!(err, LinalgError::NotSquare { rows: 1, cols: 2 }));
+    }
+
+    fn assert_qr(arr: &Array<f64, Ix<2>>) {
+        let (q, r) = arr.qr().unwrap();
+        let (m, n) = (arr.shape().dims()[0], arr.shape().dims()[1]);
+        let k = m.min(n);
+        assert_eq!(q.shape().dims(), &[m, k]);
+        assert_eq!(r.shape().dims(), &[k, n]);
+
+        let product = q.matmul(&r).unwrap();
+        for (&actual, &expected) in product.iter().zip(arr.iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+        let gram = q.transpose().matmul(&q).unwrap();
+        for (&actual, &expected) in gram.iter().zip(Array::<f64, Ix<2>>::eye(k).iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+        for i in 0..k {
+            for j in 0..i.min(n) {
+                assert_eq!(r[[i, j]], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_square_and_tall() {
+        assert_qr(&arr![
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0]
+        ]);
+        assert_qr(&arr![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+    }
+
+    #[test]
+    fn qr_wide_and_rank_deficient() {
+        assert_qr(&arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_qr(&arr![[0.0, 1.0], [0.0, 2.0], [0.0, 3.0]]);
+    }
+
+    #[test]
+    fn norm_orders() {
+        let arr = arr![3.0, -4.0];
+        assert_eq!(arr.norm(NormOrd::L2), 5.0);
+        assert_eq!(arr.norm(NormOrd::default()), 5.0);
+        assert_eq!(arr.norm(NormOrd::L1), 7.0);
+        assert_eq!(arr.norm(NormOrd::Inf), 4.0);
+        assert_eq!(Array::<f64, Ix<1>>::from_iter([]).norm(NormOrd::Inf), 0.0);
+    }
+
+    #[test]
+    fn norm_propagates_nan() {
+        for arr in [arr![1.0, f64::NAN, 3.0], arr![f64::NAN, 1.0, 3.0]] {
+            assert!(arr.norm(NormOrd::L1).is_nan());
+            assert!(arr.norm(NormOrd::L2).is_nan());
+            assert!(arr.norm(NormOrd::Inf).is_nan());
+        }
+    }
+
+    #[test]
+    fn frobenius_norm_2d() {
+        assert_eq!(arr![[1.0, 2.0], [2.0, 4.0]].frobenius_norm(), 5.0);
+    }
+
+    #[test]
+    fn batched_matmul_matches_per_slice_matmul() {
+        let lhs = [arr![[1, 2], [3, 4]], arr![[0, -1], [2, 5]]];
+        let rhs = [arr![[5, 6], [7, 8]], arr![[1, 0], [3, -2]]];
+        let product = Array::stack(&lhs, 0)
+            .unwrap()
+            .batched_matmul(&Array::stack(&rhs, 0).unwrap())
+            .unwrap();
+
+        let expected: Vec<_> = lhs
+            .iter()
+            .zip(&rhs)
+            .map(|(a, b)| a.matmul(b).unwrap())
+            .collect();
+        assert_eq!(product.shape().dims(), &[2, 2, 2]);
+        assert_eq!(product, Array::stack(&expected, 0).unwrap());
+    }
+
+    #[test]
+    fn batched_matmul_col_major() {
+        let lhs = [arr![[1, 2, 3], [4, 5, 6]], arr![[0, -1, 2], [3, 1, 1]]];
+        let rhs = [arr![[1, 0], [2, 1], [0, 3]], arr![[2, 2], [1, 0], [-1, 4]]];
+        let expected: Vec<_> = lhs
+            .iter()
+            .zip(&rhs)
+            .map(|(a, b)| a.matmul(b).unwrap())
+            .collect();
+
+        let col = |arrays: &[Array<i64, Ix<2>>]| {
+            Array::stack(arrays, 0).unwrap().to_order(Order::ColMajor)
+        };
+        let product = col(&lhs).batched_matmul(&col(&rhs)).unwrap();
+        assert_eq!(product, Array::stack(&expected, 0).unwrap());
+
+        for ((a, b), expected) in lhs.iter().zip(&rhs).zip(&expected) {
+            let (a, b) = (a.to_order(Order::ColMajor), b.to_order(Order::ColMajor));
+            assert_eq!(&a.matmul(&b).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn batched_matmul_non_square_and_mismatch() {
+        let lhs = Array::<i64, Ix<3>>::new_ones(Shape::new(Ix::new([3, 2, 4])));
+        let rhs = Array::<i64, Ix<3>>::new_ones(Shape::new(Ix::new([3, 4, 1])));
+        let product = lhs.batched_matmul(&rhs).unwrap();
+        assert_eq!(product.shape().dims(), &[3, 2, 1]);
+        assert!(product.iter().all(|&x| x == 4));
+
+        let err = lhs.batched_matmul(&lhs).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+        let other_batch = Array::<i64, Ix<3>>::new_ones(Shape::new(Ix::new([2, 4, 1])));
+        assert!(lhs.batched_matmul(&other_batch).is_err());
+    }
+}
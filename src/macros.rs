@@ -1,8 +1,37 @@
-/// The `arr!` macro is designed to accept arrays of depth 1D, 2D and 3D and flatten them into a
+/// The `arr!` macro is designed to accept arrays of depth 1D, 2D, 3D and 4D and flatten them into a
 /// single-dimensional vector. It also tracks and stores the shape (dimensions) of the array, which includes
 /// the number of rows, columns, and further dimensions as needed.
 #[macro_export]
 macro_rules! arr {
+    ($([$([$([$($elems:expr),+]),+]),+]),+ $(,)?) => {{
+        fn flatten_4d<T: Clone>(nested: &[Vec<Vec<Vec<T>>>]) -> Vec<T> {
+            nested
+                .iter()
+                .flat_map(|inner| inner.iter().flat_map(|v| v.iter().flat_map(|w| w.clone())))
+                .collect()
+        }
+
+        fn get_shape_4d<T>(nested: &[Vec<Vec<Vec<T>>>]) -> Vec<usize> {
+            let mut shape = vec![nested.len()];
+            if let Some(first) = nested.first() {
+                shape.push(first.len());
+                if let Some(second) = first.first() {
+                    shape.push(second.len());
+                    if let Some(third) = second.first() {
+                        shape.push(third.len());
+                    }
+                }
+            }
+            shape
+        }
+
+        let temp_4d = vec![$(vec![$(vec![$(vec![$($elems),+]),+]),+]),+];
+        let data_4d = flatten_4d(&temp_4d);
+        let shape_4d = get_shape_4d(&temp_4d);
+
+        $crate::Array::new(data_4d, $crate::Shape::new($crate::ix::Ix::<4>::new(shape_4d.try_into().unwrap()))).unwrap()
+    }};
+
     ($([$([$($elems:expr),+]),+]),+ $(,)?) => {{
         fn flatten_3d<T: Clone>(nested: &[Vec<Vec<T>>]) -> Vec<T> {
             nested.iter().flat_map(|inner| inner.iter().flat_map(|v| v.clone())).collect()
@@ -99,12 +128,12 @@ macro_rules! zeros {
 }
 
 /// The `ones!` macro creates a multi-dimensional array filled with ones of the specified data type,
-/// supporting 1D, 2D, and 3D arrays. It generates a flattened vector of zeros and tracks the shape
+/// supporting 1D, 2D, and 3D arrays. It generates a flattened vector of ones and tracks the shape
 /// (dimensions) of the array, which includes the number of rows, columns, and further dimensions as needed.
 #[macro_export]
 macro_rules! ones {
     ($ty:ty, $dim:expr) => {{
-        use ::num_traits::One;
+        use $crate::num_traits::One;
         let shape = vec![$dim];
         let size = shape.iter().product::<usize>();
 
@@ -116,7 +145,7 @@ macro_rules! ones {
     }};
 
     ($ty:ty, $dim1:expr, $dim2:expr) => {{
-        use ::num_traits::One;
+        use $crate::num_traits::One;
         let shape = vec![$dim1, $dim2];
         let size = shape.iter().product::<usize>();
 
@@ -128,7 +157,7 @@ macro_rules! ones {
     }};
 
     ($ty:ty, $dim1:expr, $dim2:expr, $dim3:expr) => {{
-        use ::num_traits::One;
+        use $crate::num_traits::One;
         let shape = vec![$dim1, $dim2, $dim3];
         let size = shape.iter().product::<usize>();
 
@@ -145,3 +174,120 @@ macro_rules! ones {
         panic!("Unsupported number of dimensions (only 1D, 2D, and 3D are supported): {}", dimension);
     }};
 }
+
+/// The `full!` macro creates a multi-dimensional array filled with the given value of the specified
+/// data type, supporting 1D, 2D, and 3D arrays. The value expression is evaluated once and cloned into
+/// every element of the flattened vector, and the shape (dimensions) of the array is tracked as needed.
+#[macro_export]
+macro_rules! full {
+    ($ty:ty, $value:expr, $dim:expr) => {{
+        let shape = vec![$dim];
+        let size = shape.iter().product::<usize>();
+
+        let fill_value: $ty = $value;
+        let data: Vec<$ty> = vec![fill_value; size];
+
+        let shape = $crate::Shape::new($crate::ix::Ix::<1>::new(shape.try_into().unwrap()));
+        $crate::Array::new(data, shape).unwrap()
+    }};
+
+    ($ty:ty, $value:expr, $dim1:expr, $dim2:expr) => {{
+        let shape = vec![$dim1, $dim2];
+        let size = shape.iter().product::<usize>();
+
+        let fill_value: $ty = $value;
+        let data: Vec<$ty> = vec![fill_value; size];
+
+        let shape = $crate::Shape::new($crate::ix::Ix::<2>::new(shape.try_into().unwrap()));
+        $crate::Array::new(data, shape).unwrap()
+    }};
+
+    ($ty:ty, $value:expr, $dim1:expr, $dim2:expr, $dim3:expr) => {{
+        let shape = vec![$dim1, $dim2, $dim3];
+        let size = shape.iter().product::<usize>();
+
+        let fill_value: $ty = $value;
+        let data: Vec<$ty> = vec![fill_value; size];
+
+        let shape = $crate::Shape::new($crate::ix::Ix::<3>::new(shape.try_into().unwrap()));
+        $crate::Array::new(data, shape).unwrap()
+    }};
+
+    ($ty:ty, $value:expr, $($dim:expr),+) => {{
+        let shape = vec![$($dim),+];
+        let dimension = shape.len();
+        panic!("Unsupported number of dimensions (only 1D, 2D, and 3D are supported): {}", dimension);
+    }};
+}
+
+/// The `eye!` macro creates a square identity matrix of the specified data type and size,
+/// with ones on the main diagonal and zeros elsewhere.
+#[macro_export]
+macro_rules! eye {
+    ($ty:ty, $n:expr) => {{
+        $crate::Array::<$ty, $crate::ix::Ix<2>>::eye($n)
+    }};
+}
+
+/// The `arange!` macro creates a 1D array of evenly spaced values within the half-open interval
+/// `[start, stop)`. It accepts `arange!(stop)`, `arange!(start, stop)` and `arange!(start, stop, step)`,
+/// defaulting `start` to zero and `step` to one, and panics if `step` is zero.
+#[macro_export]
+macro_rules! arange {
+    ($stop:expr) => {{
+        $crate::Array::arange(
+            $crate::num_traits::Zero::zero(),
+            $stop,
+            $crate::num_traits::One::one(),
+        )
+        .unwrap()
+    }};
+
+    ($start:expr, $stop:expr) => {{
+        $crate::Array::arange($start, $stop, $crate::num_traits::One::one()).unwrap()
+    }};
+
+    ($start:expr, $stop:expr, $step:expr) => {{
+        $crate::Array::arange($start, $stop, $step).unwrap()
+    }};
+}
+
+/// The `s!` macro builds a slice specification for `Array::slice` from one expression per axis.
+/// Each expression is either a range (`a..b`, `a..`, `..b`, `a..=b` or `..` for the full axis),
+/// which keeps the axis, or a single `usize` index, which removes the axis from the view.
+#[macro_export]
+macro_rules! s {
+    (@push $info:expr;) => {
+        $info
+    };
+
+    (@push $info:expr; $elem:expr $(, $rest:expr)* $(,)?) => {
+        $crate::s!(@push $info.push($elem); $($rest),*)
+    };
+
+    ($($elem:expr),* $(,)?) => {
+        $crate::s!(@push $crate::view::SliceInfo::empty(); $($elem),*)
+    };
+}
+
+/// The `random!` macro creates a 1D, 2D or 3D array of the given dimensions filled with values drawn
+/// uniformly from a half-open range, as in `random!(&mut rng, 0.0..1.0, rows, cols)`. It requires the
+/// `rand` feature and produces the same data whenever `rng` is seeded with the same value.
+#[cfg(feature = "rand")]
+#[macro_export]
+macro_rules! random {
+    ($rng:expr, $range:expr, $dim:expr) => {{
+        let shape = $crate::Shape::new($crate::ix::Ix::<1>::new([$dim]));
+        $crate::Array::random(shape, $range, $rng)
+    }};
+
+    ($rng:expr, $range:expr, $dim1:expr, $dim2:expr) => {{
+        let shape = $crate::Shape::new($crate::ix::Ix::<2>::new([$dim1, $dim2]));
+        $crate::Array::random(shape, $range, $rng)
+    }};
+
+    ($rng:expr, $range:expr, $dim1:expr, $dim2:expr, $dim3:expr) => {{
+        let shape = $crate::Shape::new($crate::ix::Ix::<3>::new([$dim1, $dim2, $dim3]));
+        $crate::Array::random(shape, $range, $rng)
+    }};
+}
@@ -0,0 +1,923 @@
+use crate::errors::ShapeError;
+use crate::{Array, ArrayView, Dimension, InsertAxis, Ix, RemoveAxis, Shape};
+
+/// Returns an error if `axis` is not a valid axis for arrays with `ndim` dimensions.
+fn check_axis(axis: usize, ndim: usize) -> Result<(), ShapeError> {
+    if axis >= ndim {
+        return Err(ShapeError::IncompatibleShape(format!(
+            "axis {} is out of bounds for arrays with {} dimensions",
+            axis, ndim
+        )));
+    }
+    Ok(())
+}
+
+/// Panics if `axis` is not a valid axis for arrays with `ndim` dimensions.
+pub(crate) fn assert_axis(axis: usize, ndim: usize) {
+    if axis >= ndim {
+        panic!(
+            "Axis {} is out of bounds for array with {} dimensions",
+            axis, ndim
+        );
+    }
+}
+
+impl<T: Clone, D: Dimension> Array<T, D> {
+    /// Joins arrays end-to-end along an existing axis.
+    ///
+    /// All arrays must have the same length on every axis except `axis`, and the result has
+    /// the sum of their lengths along it. Returns `ShapeError::IncompatibleShape` if no arrays
+    /// are given, the axis is out of bounds, or the other dimensions differ.
+    pub fn concatenate(arrays: &[Array<T, D>], axis: usize) -> Result<Array<T, D>, ShapeError> {
+        let first = arrays.first().ok_or_else(|| {
+            ShapeError::IncompatibleShape("cannot concatenate an empty list of arrays".to_string())
+        })?;
+        let first_dims = first.shape().dims();
+        check_axis(axis, first_dims.len())?;
+
+        for (i, array) in arrays.iter().enumerate().skip(1) {
+            let dims = array.shape().dims();
+            let matches = dims
+                .iter()
+                .zip(first_dims)
+                .enumerate()
+                .all(|(d, (a, b))| d == axis || a == b);
+            if !matches {
+                return Err(ShapeError::IncompatibleShape(format!(
+                    "cannot concatenate array {} of shape {:?} with shape {:?} along axis {}",
+                    i, dims, first_dims, axis
+                )));
+            }
+        }
+
+        let mut raw_dim = first.shape().raw_dim().clone();
+        raw_dim.dims_mut()[axis] = arrays.iter().map(|a| a.shape().dims()[axis]).sum();
+        let shape = Shape::new(raw_dim);
+
+        let (outer, _, inner) = first.shape().axis_blocks(axis);
+        let sources: Vec<_> = arrays.iter().map(|a| a.row_major_data()).collect();
+        let mut data = Vec::with_capacity(shape.size());
+        for o in 0..outer {
+            for (array, source) in arrays.iter().zip(&sources) {
+                let block = array.shape().dims()[axis] * inner;
+                data.extend_from_slice(&source[o * block..(o + 1) * block]);
+            }
+        }
+
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Stacks arrays of identical shape along a new axis of length `arrays.len()`, inserted
+    /// before `axis`, so that stacking `n` arrays of shape `[a, b]` along axis 0 gives `[n, a, b]`.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if no arrays are given, `axis` is greater than the
+    /// number of dimensions, or any array's shape differs from the first.
+    pub fn stack(arrays: &[Array<T, D>], axis: usize) -> Result<Array<T, D::Larger>, ShapeError>
+    where
+        D: InsertAxis,
+    {
+        let first = arrays.first().ok_or_else(|| {
+            ShapeError::IncompatibleShape("cannot stack an empty list of arrays".to_string())
+        })?;
+        let first_dims = first.shape().dims();
+        check_axis(axis, first_dims.len() + 1)?;
+
+        if let Some((i, array)) = arrays
+            .iter()
+            .enumerate()
+            .find(|(_, array)| array.shape().dims() != first_dims)
+        {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot stack array {} of shape {:?} with shape {:?}",
+                i,
+                array.shape().dims(),
+                first_dims
+            )));
+        }
+
+        let shape = Shape::new(first.shape().raw_dim().insert_axis(axis, arrays.len()));
+        let outer: usize = first_dims[..axis].iter().product();
+        let inner: usize = first_dims[axis..].iter().product();
+
+        let sources: Vec<_> = arrays.iter().map(|a| a.row_major_data()).collect();
+        let mut data = Vec::with_capacity(shape.size());
+        for o in 0..outer {
+            for source in &sources {
+                data.extend_from_slice(&source[o * inner..(o + 1) * inner]);
+            }
+        }
+
+        Ok(Array::from_parts(data, shape))
+    }
+
+    /// Splits the array into `sections` equal parts along `axis`.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the axis is out of bounds, `sections` is zero,
+    /// or the length of the axis is not divisible by `sections`.
+    pub fn split(&self, sections: usize, axis: usize) -> Result<Vec<Array<T, D>>, ShapeError> {
+        check_axis(axis, self.shape().dims().len())?;
+        let len = self.shape().dims()[axis];
+        if sections == 0 || len % sections != 0 {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot split axis {} of length {} into {} equal sections",
+                axis, len, sections
+            )));
+        }
+
+        let step = len / sections;
+        let indices: Vec<usize> = (1..sections).map(|i| i * step).collect();
+        self.split_at(&indices, axis)
+    }
+
+    /// Splits the array along `axis` before each of the given indices, so that the indices
+    /// `[2, 5]` split an axis of length 6 into the ranges `0..2`, `2..5` and `5..6`.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the axis is out of bounds or the indices are
+    /// not in non-decreasing order within the length of the axis.
+    pub fn split_at(&self, indices: &[usize], axis: usize) -> Result<Vec<Array<T, D>>, ShapeError> {
+        check_axis(axis, self.shape().dims().len())?;
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+
+        let mut bounds = Vec::with_capacity(indices.len() + 2);
+        bounds.push(0);
+        bounds.extend_from_slice(indices);
+        bounds.push(len);
+        if bounds.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "split indices {:?} must be non-decreasing and at most {} for axis {}",
+                indices, len, axis
+            )));
+        }
+
+        let source: &[T] = &self.row_major_data();
+        let pieces = bounds
+            .windows(2)
+            .map(|pair| {
+                let (start, end) = (pair[0], pair[1]);
+                let mut raw_dim = self.shape().raw_dim().clone();
+                raw_dim.dims_mut()[axis] = end - start;
+
+                let mut data = Vec::with_capacity(outer * (end - start) * inner);
+                for o in 0..outer {
+                    let block = o * len * inner;
+                    data.extend_from_slice(&source[block + start * inner..block + end * inner]);
+                }
+                Array::from_parts(data, Shape::new(raw_dim))
+            })
+            .collect();
+
+        Ok(pieces)
+    }
+
+    /// Repeats each slice along `axis` `repeats` times in a row, multiplying the length of the
+    /// axis by `repeats`, so that `[1, 2]` repeated twice along axis 0 gives `[1, 1, 2, 2]`.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn repeat(&self, repeats: usize, axis: usize) -> Array<T, D> {
+        assert_axis(axis, self.shape().dims().len());
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+
+        let mut raw_dim = self.shape().raw_dim().clone();
+        raw_dim.dims_mut()[axis] = len * repeats;
+        let shape = Shape::new(raw_dim);
+
+        let source: &[T] = &self.row_major_data();
+        let mut data = Vec::with_capacity(shape.size());
+        for o in 0..outer {
+            for i in 0..len {
+                let start = (o * len + i) * inner;
+                let lane = &source[start..start + inner];
+                for _ in 0..repeats {
+                    data.extend_from_slice(lane);
+                }
+            }
+        }
+
+        Array::from_parts(data, shape)
+    }
+
+    /// Places `reps` copies of the whole array end-to-end along `axis`, so that `[1, 2]` tiled
+    /// twice along axis 0 gives `[1, 2, 1, 2]`.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn tile(&self, reps: usize, axis: usize) -> Array<T, D> {
+        assert_axis(axis, self.shape().dims().len());
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+
+        let mut raw_dim = self.shape().raw_dim().clone();
+        raw_dim.dims_mut()[axis] = len * reps;
+        let shape = Shape::new(raw_dim);
+
+        let source: &[T] = &self.row_major_data();
+        let block = len * inner;
+        let mut data = Vec::with_capacity(shape.size());
+        for o in 0..outer {
+            for _ in 0..reps {
+                data.extend_from_slice(&source[o * block..(o + 1) * block]);
+            }
+        }
+
+        Array::from_parts(data, shape)
+    }
+
+    /// Rotates the elements along `axis` by `shift` positions, wrapping around the end, so that
+    /// `[1, 2, 3, 4]` rolled by 1 gives `[4, 1, 2, 3]`. Negative shifts rotate the other way, and
+    /// shifts are taken modulo the length of the axis.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn roll(&self, shift: isize, axis: usize) -> Array<T, D> {
+        assert_axis(axis, self.shape().dims().len());
+        let (_, len, inner) = self.shape().axis_blocks(axis);
+        let mut data = self.row_major_data().into_owned();
+
+        if len * inner > 0 {
+            let shift = shift.rem_euclid(len as isize) as usize;
+            for block in data.chunks_exact_mut(len * inner) {
+                block.rotate_right(shift * inner);
+            }
+        }
+
+        Array::from_parts(data, self.shape().clone())
+    }
+
+    /// Reverses the order of the slices along `axis`, returning a new array.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn flip(&self, axis: usize) -> Array<T, D> {
+        assert_axis(axis, self.shape().dims().len());
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+
+        let source: &[T] = &self.row_major_data();
+        let mut data = Vec::with_capacity(self.len());
+        for o in 0..outer {
+            for i in (0..len).rev() {
+                let start = (o * len + i) * inner;
+                data.extend_from_slice(&source[start..start + inner]);
+            }
+        }
+
+        Array::from_parts(data, self.shape().clone())
+    }
+
+    /// Returns a new array with axes `a` and `b` interchanged. The data is physically reordered
+    /// so the result stays contiguous in row-major order.
+    ///
+    /// Panics if either axis is out of bounds for the array.
+    pub fn swap_axes(&self, a: usize, b: usize) -> Array<T, D> {
+        let ndim = self.shape().dims().len();
+        if a >= ndim || b >= ndim {
+            panic!(
+                "Cannot swap axes {} and {} of array with {} dimensions",
+                a, b, ndim
+            );
+        }
+
+        let mut order: Vec<usize> = (0..ndim).collect();
+        order.swap(a, b);
+        self.permuted(&order)
+    }
+
+    /// Returns a new array whose axis `i` is axis `order[i]` of this array, for example
+    /// `permute_axes(&[1, 0])` transposes a 2D array. The data is physically reordered so the
+    /// result stays contiguous in row-major order.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if `order` is not a permutation of `0..ndim`.
+    pub fn permute_axes(&self, order: &[usize]) -> Result<Array<T, D>, ShapeError> {
+        let ndim = self.shape().dims().len();
+        let mut seen = vec![false; ndim];
+        let is_permutation = order.len() == ndim
+            && order
+                .iter()
+                .all(|&axis| axis < ndim && !std::mem::replace(&mut seen[axis], true));
+        if !is_permutation {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "axis order {:?} is not a permutation of the axes of an array with {} dimensions",
+                order, ndim
+            )));
+        }
+
+        Ok(self.permuted(order))
+    }
+
+    /// Moves axis `source` to position `destination`, keeping the other axes in order.
+    ///
+    /// Panics if either axis is out of bounds for the array.
+    pub fn move_axis(&self, source: usize, destination: usize) -> Array<T, D> {
+        let ndim = self.shape().dims().len();
+        assert_axis(source, ndim);
+        assert_axis(destination, ndim);
+
+        let mut order: Vec<usize> = (0..ndim).filter(|&axis| axis != source).collect();
+        order.insert(destination, source);
+        self.permuted(&order)
+    }
+
+    /// Reorders the axes by a permutation already known to be valid.
+    fn permuted(&self, order: &[usize]) -> Array<T, D> {
+        let mut dims = self.shape().raw_dim().clone();
+        let mut strides = self.raw_strides().clone();
+        for (i, &axis) in order.iter().enumerate() {
+            dims.dims_mut()[i] = self.shape().dims()[axis];
+            strides.dims_mut()[i] = self.strides()[axis];
+        }
+
+        // Walking a view with the permuted strides visits the elements in the new row-major order.
+        let shape = Shape::new(dims);
+        let data = ArrayView::from_parts(self.data(), shape.clone(), strides)
+            .iter()
+            .cloned()
+            .collect();
+        Array::from_parts(data, shape)
+    }
+}
+
+impl<T, D: Dimension> Array<T, D> {
+    /// Removes every axis of length 1, converting the array to the dimension type `E` of the
+    /// remaining axes. The data and memory order are unchanged.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the number of remaining axes does not match
+    /// the rank of `E`.
+    pub fn squeeze<E: Dimension>(self) -> Result<Array<T, E>, ShapeError> {
+        let dims: Vec<usize> = self
+            .shape()
+            .dims()
+            .iter()
+            .copied()
+            .filter(|&d| d != 1)
+            .collect();
+        let raw_dim = E::from_dims(&dims).ok_or_else(|| {
+            ShapeError::IncompatibleShape(format!(
+                "squeezing shape {:?} leaves {} dimensions, which does not match the target rank",
+                self.shape().dims(),
+                dims.len()
+            ))
+        })?;
+        let order = self.order();
+        Ok(Array::from_parts_in_order(
+            self.into_raw_vec(),
+            Shape::new(raw_dim),
+            order,
+        ))
+    }
+
+    /// Removes a single axis of length 1. The data and memory order are unchanged.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if the axis is out of bounds or its length is
+    /// not 1.
+    pub fn squeeze_axis(self, axis: usize) -> Result<Array<T, D::Smaller>, ShapeError>
+    where
+        D: RemoveAxis,
+    {
+        check_axis(axis, self.shape().dims().len())?;
+        let len = self.shape().dims()[axis];
+        if len != 1 {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "cannot squeeze axis {} of length {}",
+                axis, len
+            )));
+        }
+
+        let shape = Shape::new(self.shape().raw_dim().remove_axis(axis));
+        let order = self.order();
+        Ok(Array::from_parts_in_order(
+            self.into_raw_vec(),
+            shape,
+            order,
+        ))
+    }
+
+    /// Inserts a new axis of length 1 before `axis`, so that a length-3 array expanded at axis 0
+    /// has shape `[1, 3]` and at axis 1 has shape `[3, 1]`. The data and memory order are
+    /// unchanged.
+    ///
+    /// Returns `ShapeError::IncompatibleShape` if `axis` is greater than the number of dimensions.
+    pub fn expand_dims(self, axis: usize) -> Result<Array<T, D::Larger>, ShapeError>
+    where
+        D: InsertAxis,
+    {
+        check_axis(axis, self.shape().dims().len() + 1)?;
+        let shape = Shape::new(self.shape().raw_dim().insert_axis(axis, 1));
+        let order = self.order();
+        Ok(Array::from_parts_in_order(
+            self.into_raw_vec(),
+            shape,
+            order,
+        ))
+    }
+}
+
+/// Trait describing how `vstack` and `hstack` treat arrays of a given dimension type.
+///
+/// Following NumPy, 1D arrays are promoted to rows by `vstack`, which stacks them into a matrix,
+/// and joined end-to-end by `hstack`. Arrays with two or more dimensions are concatenated along
+/// axis 0 by `vstack` and along axis 1 by `hstack`.
+pub trait StackDimension: Dimension {
+    /// The dimension type of the result of `vstack`.
+    type VStacked: Dimension;
+
+    /// Stacks the arrays vertically, as rows.
+    fn vstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Self::VStacked>, ShapeError>;
+
+    /// Stacks the arrays horizontally, as columns.
+    fn hstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Self>, ShapeError>;
+}
+
+impl StackDimension for Ix<1> {
+    type VStacked = Ix<2>;
+
+    fn vstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Ix<2>>, ShapeError> {
+        Array::stack(arrays, 0)
+    }
+
+    fn hstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Self>, ShapeError> {
+        Array::concatenate(arrays, 0)
+    }
+}
+
+/// Implements `StackDimension` for a dimension type with at least two axes.
+macro_rules! impl_stack_dimension {
+    ($($n:literal),*) => {
+        $(
+            impl StackDimension for Ix<$n> {
+                type VStacked = Self;
+
+                fn vstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Self>, ShapeError> {
+                    Array::concatenate(arrays, 0)
+                }
+
+                fn hstack<T: Clone>(arrays: &[Array<T, Self>]) -> Result<Array<T, Self>, ShapeError> {
+                    Array::concatenate(arrays, 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_stack_dimension!(2, 3, 4, 5, 6);
+
+impl<T: Clone, D: StackDimension> Array<T, D> {
+    /// Stacks arrays vertically. 1D arrays are treated as rows and stacked into a 2D array,
+    /// while higher-dimensional arrays are concatenated along axis 0.
+    pub fn vstack(arrays: &[Array<T, D>]) -> Result<Array<T, D::VStacked>, ShapeError> {
+        D::vstack(arrays)
+    }
+
+    /// Stacks arrays horizontally. 1D arrays are joined end-to-end, while higher-dimensional
+    /// arrays are concatenated along axis 1.
+    pub fn hstack(arrays: &[Array<T, D>]) -> Result<Array<T, D>, ShapeError> {
+        D::hstack(arrays)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, Ix, Order, Shape, ShapeError};
+
+    #[test]
+    fn concatenate_i64_2d_axis_0() {
+        let result = Array::concatenate(&[arr![[1, 2], [3, 4]], arr![[5, 6]]], 0).unwrap();
+
+        assert_eq!(result.shape().dims(), &[3, 2]);
+        assert_eq!(result.data(), &vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn concatenate_i64_2d_axis_1() {
+        let result = Array::concatenate(&[arr![[1, 2], [3, 4]], arr![[5], [6]]], 1).unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 3]);
+        assert_eq!(result.data(), &vec![1, 2, 5, 3, 4, 6]);
+    }
+
+    #[test]
+    fn concatenate_f64_1d_three_arrays() {
+        let result = Array::concatenate(&[arr![0.5], arr![1.5, 2.5], arr![3.5]], 0).unwrap();
+
+        assert_eq!(result.shape().dims(), &[4]);
+        assert_eq!(result.data(), &vec![0.5, 1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn concatenate_f64_3d_middle_axis() {
+        let lhs = arr![[[1.0, 2.0]], [[3.0, 4.0]]];
+        let rhs = arr![[[5.0, 6.0], [7.0, 8.0]], [[9.0, 10.0], [11.0, 12.0]]];
+        let result = Array::concatenate(&[lhs, rhs], 1).unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 3, 2]);
+        assert_eq!(
+            result.data(),
+            &vec![1.0, 2.0, 5.0, 6.0, 7.0, 8.0, 3.0, 4.0, 9.0, 10.0, 11.0, 12.0]
+        );
+    }
+
+    #[test]
+    fn concatenate_errors() {
+        let err = Array::concatenate(&[arr![[1, 2], [3, 4]], arr![[5, 6, 7]]], 0).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: cannot concatenate array 1 of shape [1, 3] with shape [2, 2] along axis 0"
+        );
+
+        assert!(Array::concatenate(&[arr![1, 2]], 1).is_err());
+        assert!(Array::<i32, Ix<1>>::concatenate(&[], 0).is_err());
+    }
+
+    #[test]
+    fn stack_i64_1d_axis_0() {
+        let result = Array::stack(
+            &[arr![1, 2, 3, 4], arr![5, 6, 7, 8], arr![9, 10, 11, 12]],
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(result.shape().dims(), &[3, 4]);
+        assert_eq!(result.data(), &(1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn stack_i64_1d_axis_1() {
+        let result = Array::stack(&[arr![1, 2, 3], arr![4, 5, 6]], 1).unwrap();
+
+        assert_eq!(result.shape().dims(), &[3, 2]);
+        assert_eq!(result.data(), &vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn stack_f64_2d_middle_axis() {
+        let result = Array::stack(
+            &[arr![[0.5, 1.5], [2.5, 3.5]], arr![[4.5, 5.5], [6.5, 7.5]]],
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(result.shape().dims(), &[2, 2, 2]);
+        assert_eq!(result.data(), &vec![0.5, 1.5, 4.5, 5.5, 2.5, 3.5, 6.5, 7.5]);
+    }
+
+    #[test]
+    fn stack_errors() {
+        let err = Array::stack(&[arr![1, 2], arr![3, 4], arr![5]], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: cannot stack array 2 of shape [1] with shape [2]"
+        );
+
+        assert!(Array::stack(&[arr![1, 2]], 2).is_err());
+    }
+
+    #[test]
+    fn hstack_vstack_i64_1d() {
+        let hstacked = Array::hstack(&[arr![1, 2], arr![3, 4]]).unwrap();
+        assert_eq!(hstacked.shape().dims(), &[4]);
+        assert_eq!(hstacked.data(), &vec![1, 2, 3, 4]);
+
+        let vstacked = Array::vstack(&[arr![1, 2], arr![3, 4]]).unwrap();
+        assert_eq!(vstacked.shape().dims(), &[2, 2]);
+        assert_eq!(vstacked.data(), &vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn hstack_vstack_f64_2d() {
+        let lhs = arr![[0.5, 1.5], [2.5, 3.5]];
+        let rhs = arr![[4.5], [5.5]];
+
+        let hstacked = Array::hstack(&[lhs, rhs]).unwrap();
+        assert_eq!(hstacked.shape().dims(), &[2, 3]);
+        assert_eq!(hstacked.data(), &vec![0.5, 1.5, 4.5, 2.5, 3.5, 5.5]);
+
+        let vstacked = Array::vstack(&[hstacked, arr![[6.5, 7.5, 8.5]]]).unwrap();
+        assert_eq!(vstacked.shape().dims(), &[3, 3]);
+        assert_eq!(vstacked.data()[6..], [6.5, 7.5, 8.5]);
+
+        assert!(Array::vstack(&[arr![[1, 2]], arr![[3, 4, 5]]]).is_err());
+    }
+
+    #[test]
+    fn split_i64_1d_equal_sections() {
+        let pieces = arr![1, 2, 3, 4, 5, 6].split(3, 0).unwrap();
+
+        assert_eq!(pieces.len(), 3);
+        for (piece, expected) in pieces.iter().zip([[1, 2], [3, 4], [5, 6]]) {
+            assert_eq!(piece.shape().dims(), &[2]);
+            assert_eq!(piece.data(), &expected.to_vec());
+        }
+    }
+
+    #[test]
+    fn split_f64_2d_axis_1() {
+        let arr = arr![[0.5, 1.5, 2.5, 3.5], [4.5, 5.5, 6.5, 7.5]];
+        let pieces = arr.split(2, 1).unwrap();
+
+        assert_eq!(pieces[0].shape().dims(), &[2, 2]);
+        assert_eq!(pieces[0].data(), &vec![0.5, 1.5, 4.5, 5.5]);
+        assert_eq!(pieces[1].data(), &vec![2.5, 3.5, 6.5, 7.5]);
+    }
+
+    #[test]
+    fn split_not_divisible() {
+        let err = arr![1, 2, 3, 4, 5].split(2, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: cannot split axis 0 of length 5 into 2 equal sections"
+        );
+        assert!(arr![1, 2].split(0, 0).is_err());
+    }
+
+    #[test]
+    fn split_at_i64_2d_uneven() {
+        let arr = arr![[1, 2], [3, 4], [5, 6], [7, 8], [9, 10], [11, 12]];
+        let pieces = arr.split_at(&[2, 5], 0).unwrap();
+
+        let dims: Vec<_> = pieces.iter().map(|p| p.shape().dims().to_vec()).collect();
+        assert_eq!(dims, vec![vec![2, 2], vec![3, 2], vec![1, 2]]);
+        assert_eq!(pieces[1].data(), &vec![5, 6, 7, 8, 9, 10]);
+        assert_eq!(pieces[2].data(), &vec![11, 12]);
+
+        assert!(arr.split_at(&[4, 2], 0).is_err());
+        assert!(arr.split_at(&[7], 0).is_err());
+    }
+
+    #[test]
+    fn repeat_i64_1d() {
+        let result = arr![1, 2, 3].repeat(2, 0);
+
+        assert_eq!(result.shape().dims(), &[6]);
+        assert_eq!(result.data(), &vec![1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn repeat_f64_2d_both_axes() {
+        let arr = arr![[0.5, 1.5], [2.5, 3.5]];
+
+        let rows = arr.repeat(2, 0);
+        assert_eq!(rows.shape().dims(), &[4, 2]);
+        assert_eq!(rows.data(), &vec![0.5, 1.5, 0.5, 1.5, 2.5, 3.5, 2.5, 3.5]);
+
+        let columns = arr.repeat(3, 1);
+        assert_eq!(columns.shape().dims(), &[2, 6]);
+        assert_eq!(columns.data()[..6], [0.5, 0.5, 0.5, 1.5, 1.5, 1.5]);
+
+        assert_eq!(arr.repeat(0, 1).shape().dims(), &[2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 1 is out of bounds for array with 1 dimensions")]
+    fn repeat_invalid_axis_panics() {
+        let _ = arr![1, 2, 3].repeat(2, 1);
+    }
+
+    #[test]
+    fn tile_i64_1d() {
+        let result = arr![1, 2, 3].tile(2, 0);
+
+        assert_eq!(result.shape().dims(), &[6]);
+        assert_eq!(result.data(), &vec![1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn tile_i64_2d_matches_concatenate() {
+        let arr = arr![[1, 2], [3, 4]];
+
+        for axis in 0..2 {
+            let tiled = arr.tile(3, axis);
+            let copies = [arr.flatten(), arr.flatten(), arr.flatten()]
+                .map(|a| a.reshape(Ix::<2>::new([2, 2])).unwrap());
+            let concatenated = Array::concatenate(&copies, axis).unwrap();
+
+            assert_eq!(tiled.shape().dims(), concatenated.shape().dims());
+            assert_eq!(tiled.data(), concatenated.data());
+        }
+    }
+
+    #[test]
+    fn roll_i64_1d() {
+        let arr = arr![1, 2, 3, 4];
+
+        assert_eq!(arr.roll(1, 0).data(), &vec![4, 1, 2, 3]);
+        assert_eq!(arr.roll(-1, 0).data(), &vec![2, 3, 4, 1]);
+        assert_eq!(arr.roll(6, 0).data(), &vec![3, 4, 1, 2]);
+        assert_eq!(arr.roll(-8, 0).data(), arr.data());
+    }
+
+    #[test]
+    fn roll_f64_2d_axes() {
+        let arr = arr![[0.5, 1.5, 2.5], [3.5, 4.5, 5.5]];
+
+        assert_eq!(arr.roll(1, 0).data(), &vec![3.5, 4.5, 5.5, 0.5, 1.5, 2.5]);
+        assert_eq!(arr.roll(-1, 1).data(), &vec![1.5, 2.5, 0.5, 4.5, 5.5, 3.5]);
+        assert_eq!(arr.roll(2, 1).shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn flip_i64_2d_axes() {
+        let arr = arr![[1, 2], [3, 4]];
+
+        assert_eq!(arr.flip(0).data(), &vec![3, 4, 1, 2]);
+        assert_eq!(arr.flip(1).data(), &vec![2, 1, 4, 3]);
+        assert_eq!(arr.flip(1).shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn flip_f64_3d_middle_axis() {
+        let arr = arr![[[0.5, 1.5], [2.5, 3.5], [4.5, 5.5]]];
+        let flipped = arr.flip(1);
+
+        assert_eq!(flipped.data(), &vec![4.5, 5.5, 2.5, 3.5, 0.5, 1.5]);
+        assert_eq!(flipped.flip(1).data(), arr.data());
+    }
+
+    #[test]
+    fn axis_ops_col_major() {
+        let row = arr![[1, 2, 3], [4, 5, 6]];
+        let col = row.to_order(Order::ColMajor);
+
+        assert_eq!(col.flip(1), arr![[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(col.roll(1, 1), arr![[3, 1, 2], [6, 4, 5]]);
+        assert_eq!(col.repeat(2, 0), row.repeat(2, 0));
+        assert_eq!(col.tile(2, 1), row.tile(2, 1));
+
+        let pieces = col.split(3, 1).unwrap();
+        assert_eq!(pieces[0], arr![[1], [4]]);
+        assert_eq!(pieces[2], arr![[3], [6]]);
+    }
+
+    #[test]
+    fn concatenate_stack_col_major() {
+        let row = arr![[1, 2, 3], [4, 5, 6]];
+        let mixed = [row.to_order(Order::ColMajor), row.to_order(Order::RowMajor)];
+
+        assert_eq!(
+            Array::concatenate(&mixed, 0).unwrap(),
+            arr![[1, 2, 3], [4, 5, 6], [1, 2, 3], [4, 5, 6]]
+        );
+        assert_eq!(
+            Array::concatenate(&mixed, 1).unwrap(),
+            arr![[1, 2, 3, 1, 2, 3], [4, 5, 6, 4, 5, 6]]
+        );
+        assert_eq!(
+            Array::stack(&mixed, 0).unwrap(),
+            arr![[[1, 2, 3], [4, 5, 6]], [[1, 2, 3], [4, 5, 6]]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 is out of bounds for array with 2 dimensions")]
+    fn flip_invalid_axis_panics() {
+        let _ = arr![[1, 2], [3, 4]].flip(2);
+    }
+
+    #[test]
+    fn squeeze_i64_3d_to_1d() {
+        let arr = arr![[[1], [2], [3]]];
+        assert_eq!(arr.shape().dims(), &[1, 3, 1]);
+
+        let squeezed: Array<i32, Ix<1>> = arr.squeeze().unwrap();
+        assert_eq!(squeezed.shape().dims(), &[3]);
+        assert_eq!(squeezed.data(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn squeeze_wrong_target_rank() {
+        let arr = arr![[[1, 2]], [[3, 4]]];
+        assert!(arr.squeeze::<Ix<1>>().is_err());
+    }
+
+    #[test]
+    fn squeeze_axis_f64() {
+        let arr = arr![[[0.5, 1.5]], [[2.5, 3.5]]];
+        let squeezed = arr.squeeze_axis(1).unwrap();
+
+        assert_eq!(squeezed.shape().dims(), &[2, 2]);
+        assert_eq!(squeezed.data(), &vec![0.5, 1.5, 2.5, 3.5]);
+
+        let err = squeezed.squeeze_axis(0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: cannot squeeze axis 0 of length 2"
+        );
+    }
+
+    #[test]
+    fn expand_dims_i64_1d() {
+        let rows = arr![1, 2, 3].expand_dims(0).unwrap();
+        assert_eq!(rows.shape().dims(), &[1, 3]);
+        assert_eq!(rows.data(), &vec![1, 2, 3]);
+
+        let columns = arr![1, 2, 3].expand_dims(1).unwrap();
+        assert_eq!(columns.shape().dims(), &[3, 1]);
+
+        assert!(arr![1, 2, 3].expand_dims(2).is_err());
+    }
+
+    #[test]
+    fn expand_dims_squeeze_round_trip() {
+        let arr = arr![[0.5, 1.5], [2.5, 3.5]];
+        let expanded = arr.expand_dims(1).unwrap();
+        assert_eq!(expanded.shape().dims(), &[2, 1, 2]);
+
+        let squeezed = expanded.squeeze_axis(1).unwrap();
+        assert_eq!(squeezed.shape().dims(), &[2, 2]);
+        assert_eq!(squeezed.data(), &vec![0.5, 1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn squeeze_expand_dims_keep_col_major() {
+        let arr = arr![[[1, 3, 5]], [[2, 4, 6]]].to_order(Order::ColMajor);
+
+        let squeezed = arr.to_order(Order::ColMajor).squeeze_axis(1).unwrap();
+        assert_eq!(squeezed.order(), Order::ColMajor);
+        assert_eq!(squeezed, arr![[1, 3, 5], [2, 4, 6]]);
+        assert_eq!(arr.squeeze::<Ix<2>>().unwrap(), arr![[1, 3, 5], [2, 4, 6]]);
+
+        let expanded = arr![[1, 2], [3, 4]]
+            .to_order(Order::ColMajor)
+            .expand_dims(0)
+            .unwrap();
+        assert_eq!(expanded.order(), Order::ColMajor);
+        assert_eq!(
+            expanded.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn swap_axes_i64_3d() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<3>::new([2, 3, 4])), |ix| {
+            ix[0] * 100 + ix[1] * 10 + ix[2]
+        });
+        let swapped = arr.swap_axes(0, 2);
+
+        assert_eq!(swapped.shape().dims(), &[4, 3, 2]);
+        assert_eq!(swapped.strides(), &[6, 2, 1]);
+        assert_eq!(swapped[[3, 1, 0]], 13);
+        assert_eq!(swapped[[2, 0, 1]], 102);
+        assert_eq!(swapped.swap_axes(2, 0), arr);
+    }
+
+    #[test]
+    fn swap_axes_f64_2d_matches_transpose() {
+        let arr = arr![[0.5, 1.5, 2.5], [3.5, 4.5, 5.5]];
+
+        assert_eq!(arr.swap_axes(0, 1), arr.transpose());
+        assert_eq!(arr.swap_axes(1, 1), arr);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot swap axes 0 and 3 of array with 2 dimensions")]
+    fn swap_axes_invalid_axis_panics() {
+        let _ = arr![[1, 2], [3, 4]].swap_axes(0, 3);
+    }
+
+    #[test]
+    fn permute_axes_2d_is_transpose() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(arr.permute_axes(&[1, 0]).unwrap(), arr.transpose());
+        assert_eq!(arr.permute_axes(&[0, 1]).unwrap(), arr);
+    }
+
+    #[test]
+    fn permute_axes_f64_3d() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<3>::new([2, 3, 4])), |ix| {
+            (ix[0] * 100 + ix[1] * 10 + ix[2]) as f64
+        });
+        let permuted = arr.permute_axes(&[2, 0, 1]).unwrap();
+
+        assert_eq!(permuted.shape().dims(), &[4, 2, 3]);
+        assert_eq!(permuted[[3, 1, 2]], 123.0);
+        assert_eq!(permuted[[0, 0, 1]], 10.0);
+    }
+
+    #[test]
+    fn permute_axes_invalid_order() {
+        let arr = arr![[[1, 2]], [[3, 4]]];
+
+        let err = arr.permute_axes(&[0, 0, 1]).unwrap_err();
+        assert!(matches!(err, ShapeError::IncompatibleShape(_)));
+        assert_eq!(
+            err.to_string(),
+            "Incompatible shape: axis order [0, 0, 1] is not a permutation of the axes of an array with 3 dimensions"
+        );
+        assert!(arr.permute_axes(&[0, 1]).is_err());
+        assert!(arr.permute_axes(&[0, 1, 3]).is_err());
+    }
+
+    #[test]
+    fn move_axis_i64_3d() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<3>::new([2, 3, 4])), |ix| {
+            ix[0] * 100 + ix[1] * 10 + ix[2]
+        });
+
+        let moved = arr.move_axis(0, 2);
+        assert_eq!(moved.shape().dims(), &[3, 4, 2]);
+        assert_eq!(moved[[2, 3, 1]], 123);
+        assert_eq!(moved.move_axis(2, 0), arr);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 3 is out of bounds for array with 2 dimensions")]
+    fn move_axis_invalid_axis_panics() {
+        let _ = arr![[1, 2], [3, 4]].move_axis(3, 0);
+    }
+}
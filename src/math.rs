@@ -0,0 +1,370 @@
+use std::ops::Sub;
+
+use num_traits::{Float, PrimInt, Signed};
+
+use crate::manipulation::assert_axis;
+use crate::{Array, Dimension, Ix, Shape};
+
+impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
+    /// Returns a new array with every element limited to the range `[min, max]`.
+    ///
+    /// Panics if `min` is greater than `max`, or if either bound is unordered such as `NaN`.
+    pub fn clamp(&self, min: T, max: T) -> Self {
+        assert!(min <= max, "clamp requires min <= max");
+        self.map(|&x| clamp_value(x, min, max))
+    }
+
+    /// Limits every element to the range `[min, max]` in place.
+    ///
+    /// Panics if `min` is greater than `max`, or if either bound is unordered such as `NaN`.
+    pub fn clamp_inplace(&mut self, min: T, max: T) {
+        assert!(min <= max, "clamp requires min <= max");
+        self.mapv_inplace(|x| clamp_value(x, min, max));
+    }
+}
+
+impl<T: Signed + Copy, D: Dimension> Array<T, D> {
+    /// Returns a new array with the absolute value of every element.
+    pub fn abs(&self) -> Self {
+        self.map(|x| x.abs())
+    }
+}
+
+/// Implements element-wise methods that apply the `Float` method of the same name to every element.
+macro_rules! impl_float_fns {
+    ($($(#[$doc:meta])* $method:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $method(&self) -> Self {
+                self.map(|x| x.$method())
+            }
+        )*
+    };
+}
+
+impl<T: Float, D: Dimension> Array<T, D> {
+    impl_float_fns!(
+        /// Returns a new array with the square root of every element. Negative elements give `NaN`.
+        sqrt,
+        /// Returns a new array with `e` raised to the power of every element.
+        exp,
+        /// Returns a new array with the natural logarithm of every element. Negative elements
+        /// give `NaN` and zero gives negative infinity.
+        ln,
+        /// Returns a new array with the sine of every element, in radians.
+        sin,
+        /// Returns a new array with the cosine of every element, in radians.
+        cos,
+        /// Returns a new array with the tangent of every element, in radians.
+        tan,
+        /// Returns a new array with every element rounded down to the nearest integer.
+        floor,
+        /// Returns a new array with every element rounded up to the nearest integer.
+        ceil,
+        /// Returns a new array with every element rounded to the nearest integer, rounding
+        /// half-way cases away from zero, so that `1.5` becomes `2.0` and `-1.5` becomes `-2.0`.
+        /// Note that this differs from NumPy, which rounds half-way cases to even.
+        round,
+    );
+
+    /// Returns a new array with every element raised to the integer power `n`.
+    pub fn powi(&self, n: i32) -> Self {
+        self.map(|x| x.powi(n))
+    }
+
+    /// Returns a new array with every element raised to the floating-point power `p`.
+    pub fn powf(&self, p: T) -> Self {
+        self.map(|x| x.powf(p))
+    }
+}
+
+impl<T: PrimInt, D: Dimension> Array<T, D> {
+    /// Returns a new array with every element of an integer array raised to the power `n`.
+    /// Overflow follows the semantics of the integer type's `pow`.
+    pub fn pow(&self, n: u32) -> Self {
+        self.map(|x| x.pow(n))
+    }
+}
+
+/// Limits `x` to `[min, max]`, leaving unordered values such as `NaN` unchanged.
+fn clamp_value<T: PartialOrd>(x: T, min: T, max: T) -> T {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+impl<T: Sub<Output = T> + Copy, D: Dimension> Array<T, D> {
+    /// Computes the difference between consecutive elements along `axis`, `a[i + 1] - a[i]`,
+    /// returning an array whose length on that axis is one shorter. An axis of length 1 gives
+    /// an empty result.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn diff(&self, axis: usize) -> Self {
+        assert_axis(axis, self.shape().dims().len());
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+        let out_len = len.saturating_sub(1);
+
+        let data: &[T] = &self.row_major_data();
+        let mut out = Vec::with_capacity(outer * out_len * inner);
+        for o in 0..outer {
+            for i in 0..out_len {
+                let start = (o * len + i) * inner;
+                let (current, next) = data[start..start + 2 * inner].split_at(inner);
+                out.extend(next.iter().zip(current).map(|(&b, &a)| b - a));
+            }
+        }
+
+        let mut dims = self.shape().raw_dim().clone();
+        dims.dims_mut()[axis] = out_len;
+        Array::from_parts(out, Shape::new(dims))
+    }
+
+    /// Computes the `n`-th order difference along `axis` by applying `diff` `n` times, so the
+    /// axis shrinks by `n`, stopping at zero. An `n` of zero returns a copy of the array.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn diff_n(&self, n: usize, axis: usize) -> Self {
+        assert_axis(axis, self.shape().dims().len());
+        (0..n).fold(self.map(|&x| x), |acc, _| acc.diff(axis))
+    }
+}
+
+impl<T: Into<f64> + Copy> Array<T, Ix<1>> {
+    /// Estimates the derivative of evenly spaced samples, with `spacing` between neighbouring
+    /// samples, like NumPy's `gradient`. Interior points use the central difference
+    /// `(a[i + 1] - a[i - 1]) / (2 * spacing)`, while the first and last points use the forward
+    /// and backward differences.
+    ///
+    /// Panics if the array has fewer than two elements.
+    pub fn gradient(&self, spacing: f64) -> Array<f64, Ix<1>> {
+        let values: Vec<f64> = self.iter().map(|&x| x.into()).collect();
+        let n = values.len();
+        assert!(n >= 2, "gradient requires at least 2 elements, got {}", n);
+
+        let mut out = Vec::with_capacity(n);
+        out.push((values[1] - values[0]) / spacing);
+        out.extend(values.windows(3).map(|w| (w[2] - w[0]) / (2.0 * spacing)));
+        out.push((values[n - 1] - values[n - 2]) / spacing);
+        Array::from_parts(out, Shape::new(Ix::<1>::new([n])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, PI};
+
+    use crate::{Array, Ix, Order};
+
+    #[test]
+    fn clamp_i64_1d() {
+        let arr = arr![-5, 0, 5, 10];
+        let clamped = arr.clamp(0, 5);
+
+        assert_eq!(clamped.data(), &vec![0, 0, 5, 5]);
+        assert_eq!(arr.data(), &vec![-5, 0, 5, 10]);
+    }
+
+    #[test]
+    fn clamp_inplace_f64_2d() {
+        let mut arr = arr![[-1.5, 0.25], [0.75, 2.0]];
+        arr.clamp_inplace(0.0, 1.0);
+
+        assert_eq!(arr.data(), &vec![0.0, 0.25, 0.75, 1.0]);
+        assert_eq!(arr.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn clamp_keeps_nan() {
+        let arr = arr![f64::NAN, 3.0];
+        let clamped = arr.clamp(0.0, 1.0);
+
+        assert!(clamped.data()[0].is_nan());
+        assert_eq!(clamped.data()[1], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp requires min <= max")]
+    fn clamp_invalid_bounds_panics() {
+        let _ = arr![1, 2, 3].clamp(3, 1);
+    }
+
+    #[test]
+    fn abs_i64_2d() {
+        let arr = arr![[-1, 2], [-3, -4]];
+        let result = arr.abs();
+
+        assert_eq!(result.data(), &vec![1, 2, 3, 4]);
+        assert_eq!(result.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn abs_f64_1d() {
+        let arr = arr![-0.5, 1.5, -0.0];
+        assert_eq!(arr.abs().data(), &vec![0.5, 1.5, 0.0]);
+    }
+
+    #[test]
+    fn sqrt_exp_ln_f64_1d() {
+        let arr = arr![0.0, 1.0, 4.0, 2.25];
+
+        assert_eq!(arr.sqrt().data(), &vec![0.0, 1.0, 2.0, 1.5]);
+        assert_eq!(arr![0.0, 1.0].exp().data(), &vec![1.0, E]);
+        assert_eq!(arr![1.0, E].ln().data(), &vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn sqrt_ln_negative_is_nan() {
+        let arr = arr![-1.0_f64, 0.0];
+
+        assert!(arr.sqrt().data()[0].is_nan());
+        assert!(arr.ln().data()[0].is_nan());
+        assert_eq!(arr.ln().data()[1], f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn exp_ln_f32_2d_round_trip() {
+        let arr: Array<f32, Ix<2>> = arr![[0.5, 1.5], [2.5, 3.5]];
+        let result = arr.exp().ln();
+
+        assert_eq!(result.shape().dims(), &[2, 2]);
+        for (x, y) in result.iter().zip(arr.iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn sin_cos_tan_f64_1d() {
+        let arr = arr![0.0, PI];
+        let sin = arr.sin();
+        let cos = arr.cos();
+
+        assert!(sin.iter().all(|x| x.abs() < 1e-12));
+        assert_eq!(cos.data(), &vec![1.0, -1.0]);
+        assert!((arr![PI / 4.0].tan().data()[0] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sin_cos_f32_2d_identity() {
+        let arr: Array<f32, Ix<2>> = arr![[0.5, -1.5], [2.5, 3.5]];
+        let sin = arr.sin();
+        let cos = arr.cos();
+
+        assert_eq!(sin.shape().dims(), &[2, 2]);
+        for (s, c) in sin.iter().zip(cos.iter()) {
+            assert!((s * s + c * c - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn powi_powf_f64_1d() {
+        let arr = arr![1.0, 2.0, 3.0];
+
+        assert_eq!(arr.powi(2).data(), &vec![1.0, 4.0, 9.0]);
+        assert_eq!(arr.powi(-1).data(), &vec![1.0, 0.5, 1.0 / 3.0]);
+        assert_eq!(arr![4.0, 9.0].powf(0.5).data(), &vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn pow_i64_2d() {
+        let arr = arr![[1, -2], [3, 4]];
+        let result = arr.pow(3);
+
+        assert_eq!(result.data(), &vec![1, -8, 27, 64]);
+        assert_eq!(result.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn floor_ceil_round_f64_1d() {
+        let arr = arr![1.2, 1.5, -1.5, 2.7];
+
+        assert_eq!(arr.floor().data(), &vec![1.0, 1.0, -2.0, 2.0]);
+        assert_eq!(arr.ceil().data(), &vec![2.0, 2.0, -1.0, 3.0]);
+        assert_eq!(arr.round().data(), &vec![1.0, 2.0, -2.0, 3.0]);
+    }
+
+    #[test]
+    fn round_f32_2d_half_away_from_zero() {
+        let arr: Array<f32, Ix<2>> = arr![[0.5, 2.5], [-0.5, -2.4]];
+        let result = arr.round();
+
+        assert_eq!(result.shape().dims(), &[2, 2]);
+        assert_eq!(result.data(), &vec![1.0, 3.0, -1.0, -2.0]);
+    }
+
+    #[test]
+    fn diff_i64_1d() {
+        let diff = arr![1, 2, 4, 7].diff(0);
+
+        assert_eq!(diff.shape().dims(), &[3]);
+        assert_eq!(diff.data(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn diff_f64_2d_axes() {
+        let arr = arr![[0.5, 1.5, 4.5], [2.0, 1.0, 0.0]];
+
+        let rows = arr.diff(0);
+        assert_eq!(rows.shape().dims(), &[1, 3]);
+        assert_eq!(rows.data(), &vec![1.5, -0.5, -4.5]);
+
+        let cols = arr.diff(1);
+        assert_eq!(cols.shape().dims(), &[2, 2]);
+        assert_eq!(cols.data(), &vec![1.0, 3.0, -1.0, -1.0]);
+    }
+
+    #[test]
+    fn diff_n_and_short_axes() {
+        let arr = arr![1, 4, 9, 16, 25];
+        assert_eq!(arr.diff_n(2, 0).data(), &vec![2, 2, 2]);
+        assert_eq!(arr.diff_n(0, 0), arr);
+        assert_eq!(arr.diff_n(7, 0).shape().dims(), &[0]);
+
+        let single = arr![[1, 2, 3]].diff(0);
+        assert_eq!(single.shape().dims(), &[0, 3]);
+        assert!(single.is_empty());
+    }
+
+    #[test]
+    fn diff_col_major() {
+        let arr = arr![[1, 4, 9], [16, 25, 36]].to_order(Order::ColMajor);
+
+        assert_eq!(arr.diff(1), arr![[3, 5], [9, 11]]);
+        assert_eq!(arr.diff(0), arr![[15, 21, 27]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 1 is out of bounds for array with 1 dimensions")]
+    fn diff_invalid_axis_panics() {
+        let _ = arr![1, 2, 3].diff(1);
+    }
+
+    #[test]
+    fn gradient_f64_unit_spacing() {
+        let grad = arr![1.0, 2.0, 4.0, 7.0].gradient(1.0);
+
+        assert_eq!(grad.shape().dims(), &[4]);
+        assert_eq!(grad.data(), &vec![1.0, 1.5, 2.5, 3.0]);
+    }
+
+    #[test]
+    fn gradient_boundaries_and_spacing() {
+        // The ends use one-sided differences over a single step.
+        let grad = arr![0, 1, 4, 9, 16].gradient(0.5);
+        assert_eq!(grad.data()[0], 2.0);
+        assert_eq!(grad.data()[4], 14.0);
+        assert_eq!(grad.data()[1..4], [4.0, 8.0, 12.0]);
+
+        // With two samples, both ends are the same forward/backward difference.
+        assert_eq!(arr![3.0, -1.0].gradient(2.0).data(), &vec![-2.0, -2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "gradient requires at least 2 elements, got 1")]
+    fn gradient_single_element_panics() {
+        let _ = arr![1.0].gradient(1.0);
+    }
+}
@@ -0,0 +1,107 @@
+use ndarray::{Dim, ShapeBuilder};
+
+use crate::{Array, Dimension, Ix, Order, Shape};
+
+/// Implements conversions between `Array<T, Ix<N>>` and `ndarray::Array<T, Dim<[usize; N]>>`.
+macro_rules! impl_ndarray_conversions {
+    ($($n:literal),*) => {
+        $(
+            impl<T: Clone> From<ndarray::Array<T, Dim<[usize; $n]>>> for Array<T, Ix<$n>> {
+                /// Converts an `ndarray` array, reusing its buffer when it is already stored
+                /// contiguously in row-major order and copying the elements otherwise.
+                fn from(array: ndarray::Array<T, Dim<[usize; $n]>>) -> Self {
+                    let shape = Shape::new(
+                        Ix::from_dims(array.shape()).expect("ndarray shape matches the rank"),
+                    );
+                    let data = if array.is_standard_layout() {
+                        // The buffer can extend past the elements on either side, such as after
+                        // slicing, so keep only the `shape.size()` elements from the offset.
+                        let (mut data, offset) = array.into_raw_vec_and_offset();
+                        let offset = offset.unwrap_or(0);
+                        data.truncate(offset + shape.size());
+                        data.drain(..offset);
+                        data
+                    } else {
+                        array.iter().cloned().collect()
+                    };
+                    Array::from_parts(data, shape)
+                }
+            }
+
+            impl<T> From<Array<T, Ix<$n>>> for ndarray::Array<T, Dim<[usize; $n]>> {
+                /// Converts into an `ndarray` array with the same memory order, reusing the buffer.
+                fn from(array: Array<T, Ix<$n>>) -> Self {
+                    let mut dims = [0; $n];
+                    dims.copy_from_slice(array.shape().dims());
+                    let shape = dims.set_f(array.order() == Order::ColMajor);
+                    ndarray::Array::from_shape_vec(shape, array.into_raw_vec())
+                        .expect("data length matches the shape")
+                }
+            }
+        )*
+    };
+}
+
+impl_ndarray_conversions!(1, 2, 3, 4, 5, 6);
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array2, Array3};
+
+    use crate::{Array, Ix, Order};
+
+    #[test]
+    fn ndarray_round_trip_i64_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let converted: Array2<i32> = arr.into();
+        assert_eq!(converted, array![[1, 2, 3], [4, 5, 6]]);
+
+        let back: Array<i32, Ix<2>> = converted.into();
+        assert_eq!(back.shape().dims(), &[2, 3]);
+        assert_eq!(back.data(), &vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn ndarray_from_f64_1d_and_3d() {
+        let vector: Array<f64, Ix<1>> = array![0.5, 1.5, 2.5].into();
+        assert_eq!(vector.data(), &vec![0.5, 1.5, 2.5]);
+
+        let volume = Array3::from_shape_fn((2, 1, 2), |(i, _, k)| (i * 2 + k) as f64);
+        let volume: Array<f64, Ix<3>> = volume.into();
+        assert_eq!(volume.shape().dims(), &[2, 1, 2]);
+        assert_eq!(volume.data(), &vec![0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn ndarray_from_non_standard_layout() {
+        let transposed = array![[1, 2, 3], [4, 5, 6]].reversed_axes();
+        let arr: Array<i32, Ix<2>> = transposed.into();
+        assert_eq!(arr.shape().dims(), &[3, 2]);
+        assert_eq!(arr.data(), &vec![1, 4, 2, 5, 3, 6]);
+
+        let mut sliced = array![[1, 2, 3], [4, 5, 6]];
+        sliced.slice_collapse(ndarray::s![1.., ..]);
+        let arr: Array<i32, Ix<2>> = sliced.into();
+        assert_eq!(arr.data(), &vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn ndarray_from_narrowed_at_zero_offset() {
+        let mut sliced = array![[1, 2, 3], [4, 5, 6]];
+        sliced.slice_collapse(ndarray::s![..1, ..]);
+        let arr: Array<i32, Ix<2>> = sliced.into();
+
+        assert_eq!(arr.shape().dims(), &[1, 3]);
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.data(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn ndarray_from_col_major_array() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]].to_order(Order::ColMajor);
+        let converted: Array2<i32> = arr.into();
+
+        assert_eq!(converted, array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(converted.strides(), &[1, 2]);
+    }
+}
@@ -102,17 +102,17 @@ where
 
 impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
     /// Starts building a computation for the maximum values of this array.
-    pub fn max(&self) -> MaxBuilder<T, D> {
+    pub fn max(&self) -> MaxBuilder<'_, T, D> {
         MaxBuilder::new(self)
     }
 
     /// Starts building a computation for the minimum values of this array.
-    pub fn min(&self) -> MinBuilder<T, D> {
+    pub fn min(&self) -> MinBuilder<'_, T, D> {
         MinBuilder::new(self)
     }
 
     /// Starts building a computation for the mean values of this array.
-    pub fn mean(&self) -> MeanBuilder<T, D> 
+    pub fn mean(&self) -> MeanBuilder<'_, T, D>
     where 
         T: Into<f64>
     {
@@ -0,0 +1,76 @@
+use num_traits::Zero;
+use rayon::prelude::*;
+
+use crate::{Array, Dimension};
+
+impl<T: Sync, D: Dimension> Array<T, D> {
+    /// Applies `f` to every element in parallel and returns a new array of the same shape. The
+    /// result is identical to `map`, since each output element depends only on its input.
+    pub fn par_map<U, F>(&self, f: F) -> Array<U, D>
+    where
+        U: Send,
+        F: Fn(&T) -> U + Sync + Send,
+    {
+        let data: Vec<U> = self.data().par_iter().map(f).collect();
+        Array::from_parts_in_order(data, self.shape().clone(), self.order())
+    }
+}
+
+impl<T: Zero + Copy + Send + Sync, D: Dimension> Array<T, D> {
+    /// Sums all elements with a parallel reduction, returning zero for an empty array.
+    ///
+    /// The elements are added in a different grouping from `sum`, so for floating-point types
+    /// the result may differ from the serial sum in the last few bits. For integers the result
+    /// is identical.
+    pub fn par_sum(&self) -> T {
+        self.data()
+            .par_iter()
+            .copied()
+            .reduce(T::zero, |acc, x| acc + x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, Ix, Shape};
+
+    #[test]
+    fn par_map_matches_map_large_f64() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<2>::new([300, 500])), |ix| {
+            (ix[0] * 500 + ix[1]) as f64 * 0.25
+        });
+        let f = |x: &f64| x.sqrt() * 3.0 - 1.0;
+
+        let parallel = arr.par_map(f);
+        assert_eq!(parallel.shape().dims(), &[300, 500]);
+        assert_eq!(parallel, arr.map(f));
+    }
+
+    #[test]
+    fn par_map_i64_to_bool_3d() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<3>::new([4, 5, 6])), |ix| {
+            (ix[0] * 30 + ix[1] * 6 + ix[2]) as i64
+        });
+
+        assert_eq!(arr.par_map(|&x| x % 3 == 0), arr.map(|&x| x % 3 == 0));
+    }
+
+    #[test]
+    fn par_sum_matches_sum_large_i64() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<2>::new([400, 600])), |ix| {
+            (ix[0] * 600 + ix[1]) as i64 - 100_000
+        });
+
+        assert_eq!(arr.par_sum(), arr.sum());
+        assert_eq!(arr.par_sum(), 4_799_880_000);
+    }
+
+    #[test]
+    fn par_sum_f64_and_empty() {
+        let arr = Array::from_shape_fn(Shape::new(Ix::<1>::new([1024])), |ix| ix[0] as f64 * 0.5);
+        assert_eq!(arr.par_sum(), 261_888.0);
+
+        let empty = Array::<f64, Ix<1>>::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert_eq!(empty.par_sum(), 0.0);
+    }
+}
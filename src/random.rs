@@ -0,0 +1,60 @@
+use std::ops::Range;
+
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::Uniform;
+use rand::Rng;
+
+use crate::{Array, Dimension, Shape};
+
+impl<T: SampleUniform, D: Dimension> Array<T, D> {
+    /// Creates an array of the given shape filled with values drawn uniformly from the half-open
+    /// `range` using `rng`. Seeding `rng` makes the generated data reproducible.
+    ///
+    /// Panics if `range` is empty.
+    pub fn random<R: Rng + ?Sized>(shape: Shape<D>, range: Range<T>, rng: &mut R) -> Self {
+        let distribution = Uniform::from(range);
+        let data: Vec<T> = (0..shape.size())
+            .map(|_| rng.sample(&distribution))
+            .collect();
+        Array::from_parts(data, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{Array, Ix, Shape};
+
+    #[test]
+    fn random_f64_2d_in_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let arr = Array::random(Shape::new(Ix::<2>::new([3, 4])), -1.0..1.0, &mut rng);
+
+        assert_eq!(arr.shape().dims(), &[3, 4]);
+        assert!(arr.iter().all(|&x| (-1.0..1.0).contains(&x)));
+    }
+
+    #[test]
+    fn random_same_seed_same_data() {
+        let first = random!(&mut StdRng::seed_from_u64(42), 0..100, 2, 3, 4);
+        let second = random!(&mut StdRng::seed_from_u64(42), 0..100, 2, 3, 4);
+
+        assert_eq!(first.shape().dims(), &[2, 3, 4]);
+        assert_eq!(first.data(), second.data());
+        assert!(first.iter().all(|&x| (0..100).contains(&x)));
+    }
+
+    #[test]
+    fn random_macro_1d_and_2d() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let vector = random!(&mut rng, 0.0_f32..0.5, 5);
+        let matrix = random!(&mut rng, 10_u8..20, 2, 2);
+
+        assert_eq!(vector.shape().dims(), &[5]);
+        assert!(vector.iter().all(|&x| (0.0..0.5).contains(&x)));
+        assert_eq!(matrix.shape().dims(), &[2, 2]);
+        assert!(matrix.iter().all(|&x| (10..20).contains(&x)));
+    }
+}
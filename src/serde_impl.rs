@@ -0,0 +1,154 @@
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Array, Dimension, Ix, IxDyn, Shape};
+
+impl<const N: usize> Serialize for Ix<N> {
+    /// Serializes the dimensions as a sequence of lengths.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.dims())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Ix<N> {
+    /// Deserializes the dimensions from a sequence of exactly `N` lengths.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let dims = Vec::<usize>::deserialize(deserializer)?;
+        Ix::from_dims(&dims).ok_or_else(|| {
+            de::Error::invalid_length(dims.len(), &format!("{} dimensions", N).as_str())
+        })
+    }
+}
+
+impl Serialize for IxDyn {
+    /// Serializes the dimensions as a sequence of lengths.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.dims())
+    }
+}
+
+impl<'de> Deserialize<'de> for IxDyn {
+    /// Deserializes the dimensions from a sequence of any number of lengths.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<usize>::deserialize(deserializer).map(|dims| IxDyn::new(&dims))
+    }
+}
+
+impl<D: Dimension + Serialize> Serialize for Shape<D> {
+    /// Serializes the shape as its underlying dimensions.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw_dim().serialize(serializer)
+    }
+}
+
+impl<'de, D: Dimension + Deserialize<'de>> Deserialize<'de> for Shape<D> {
+    /// Deserializes the shape from its underlying dimensions.
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        D::deserialize(deserializer).map(Shape::new)
+    }
+}
+
+/// The serialized form of an array: its shape together with the flat row-major data.
+#[derive(serde::Serialize)]
+struct ArrayRef<'a, T, D: Dimension> {
+    shape: &'a Shape<D>,
+    data: Elements<'a, T, D>,
+}
+
+/// The elements of an array, serialized as a sequence in row-major order whatever the memory
+/// order of the array.
+struct Elements<'a, T, D: Dimension>(&'a Array<T, D>);
+
+impl<T: Serialize, D: Dimension> Serialize for Elements<'_, T, D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct ArrayOwned<T, D: Dimension> {
+    shape: Shape<D>,
+    data: Vec<T>,
+}
+
+impl<T: Serialize, D: Dimension + Serialize> Serialize for Array<T, D> {
+    /// Serializes the array as a struct holding its shape and flat row-major data.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ArrayRef {
+            shape: self.shape(),
+            data: Elements(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, D> Deserialize<'de> for Array<T, D>
+where
+    T: Deserialize<'de>,
+    D: Dimension + Deserialize<'de>,
+{
+    /// Deserializes an array from its shape and flat row-major data, failing if the number of
+    /// elements does not match the shape.
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let ArrayOwned { shape, data } = ArrayOwned::deserialize(deserializer)?;
+        Array::new(data, shape).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, ArrayDyn, Ix, Order};
+
+    #[test]
+    fn serde_round_trip_i64_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let json = serde_json::to_string(&arr).unwrap();
+        assert_eq!(json, r#"{"shape":[2,3],"data":[1,2,3,4,5,6]}"#);
+
+        let parsed: Array<i32, Ix<2>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.shape().dims(), arr.shape().dims());
+        assert_eq!(parsed.data(), arr.data());
+    }
+
+    #[test]
+    fn serde_round_trip_f64_3d() {
+        let arr = arr![[[0.5, -1.5]], [[2.25, 3.0]]];
+        let json = serde_json::to_string(&arr).unwrap();
+
+        let parsed: Array<f64, Ix<3>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.shape().dims(), &[2, 1, 2]);
+        assert_eq!(parsed.data(), arr.data());
+    }
+
+    #[test]
+    fn serde_rejects_mismatched_data_length() {
+        let json = r#"{"shape":[2,2],"data":[1,2,3]}"#;
+        let err = serde_json::from_str::<Array<i64, Ix<2>>>(json).unwrap_err();
+        assert!(err.to_string().starts_with("Dimension mismatch"));
+    }
+
+    #[test]
+    fn serde_rejects_wrong_rank() {
+        let json = r#"{"shape":[4],"data":[1,2,3,4]}"#;
+        assert!(serde_json::from_str::<Array<i64, Ix<2>>>(json).is_err());
+    }
+
+    #[test]
+    fn serde_round_trip_dyn() {
+        let json = r#"{"shape":[2,1,2],"data":[1,2,3,4]}"#;
+        let parsed: ArrayDyn<i64> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(parsed.shape().dims(), &[2, 1, 2]);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn serde_col_major_serializes_row_major() {
+        let arr = arr![[0.5, 1.5], [2.5, 3.5]].to_order(Order::ColMajor);
+
+        assert_eq!(
+            serde_json::to_string(&arr).unwrap(),
+            r#"{"shape":[2,2],"data":[0.5,1.5,2.5,3.5]}"#
+        );
+    }
+}
@@ -2,6 +2,19 @@ use std::fmt::{Debug, Formatter, Result};
 
 use crate::Dimension;
 
+/// The order in which the elements of an array are laid out in memory.
+///
+/// Whatever the memory order, an array's elements are indexed and iterated in the same logical
+/// row-major order; only the strides and the backing buffer differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// C order: the last axis is contiguous in memory.
+    #[default]
+    RowMajor,
+    /// Fortran order: the first axis is contiguous in memory.
+    ColMajor,
+}
+
 /// Represents the shape of an array or matrix, encapsulating the dimensions.
 ///
 /// This structure wraps a type that implements `Dimension`, allowing for
@@ -36,6 +49,72 @@ where
     pub fn dims(&self) -> &[usize] {
         self.dims.dims()
     }
+
+    /// Returns the row-major strides of the shape: the number of elements to step over in the
+    /// flat data to move by one along each axis.
+    pub fn strides(&self) -> Vec<usize> {
+        let dims = self.dims();
+        let mut strides = vec![1; dims.len()];
+        for i in (0..dims.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * dims[i + 1];
+        }
+        strides
+    }
+
+    /// Returns the strides of the shape for data laid out in `order`, as a dimension of the same
+    /// rank.
+    pub(crate) fn strides_for(&self, order: Order) -> D {
+        let mut strides = self.dims.clone();
+        let mut stride = 1;
+        let mut assign = |(s, &dim): (&mut usize, &usize)| {
+            *s = stride;
+            stride *= dim;
+        };
+        let pairs = strides.dims_mut().iter_mut().zip(self.dims());
+        match order {
+            Order::RowMajor => pairs.rev().for_each(&mut assign),
+            Order::ColMajor => pairs.for_each(&mut assign),
+        }
+        strides
+    }
+
+    /// Converts a flat row-major index into the coordinate it refers to.
+    pub(crate) fn unravel(&self, mut index: usize) -> D {
+        let mut coords = self.dims.clone();
+        for (coord, &dim) in coords.dims_mut().iter_mut().zip(self.dims()).rev() {
+            *coord = index % dim;
+            index /= dim;
+        }
+        coords
+    }
+
+    /// Splits the shape around `axis` into `(outer, len, inner)` for row-major data: the number of
+    /// blocks before the axis, the length of the axis, and the number of contiguous elements
+    /// per step along it. Element `i` of block `o` at inner position `k` is at
+    /// `o * len * inner + i * inner + k`.
+    pub(crate) fn axis_blocks(&self, axis: usize) -> (usize, usize, usize) {
+        let dims = self.dims();
+        let outer = dims[..axis].iter().product();
+        let inner = dims[axis + 1..].iter().product();
+        (outer, dims[axis], inner)
+    }
+}
+
+/// Computes the offset of a coordinate into a buffer laid out with the given dimensions and
+/// strides, or `None` if it has the wrong number of dimensions or any component is out of bounds.
+pub(crate) fn strided_offset(dims: &[usize], strides: &[usize], index: &[usize]) -> Option<usize> {
+    let in_bounds = index.len() == dims.len() && index.iter().zip(dims).all(|(&i, &dim)| i < dim);
+    in_bounds.then(|| strided_offset_unchecked(strides, index))
+}
+
+/// Computes the offset of a coordinate already known to be in bounds into a buffer laid out with
+/// the given strides.
+pub(crate) fn strided_offset_unchecked(strides: &[usize], index: &[usize]) -> usize {
+    index
+        .iter()
+        .zip(strides)
+        .map(|(&i, &stride)| i * stride)
+        .sum()
 }
 
 impl<D> Debug for Shape<D>
@@ -57,3 +136,18 @@ where
         Shape { dims: dimension }
     }
 }
+
+impl<D: Dimension> PartialEq<[usize]> for Shape<D> {
+    /// Compares the dimensions of the shape with a slice of lengths.
+    fn eq(&self, other: &[usize]) -> bool {
+        self.dims() == other
+    }
+}
+
+impl<D: Dimension, const N: usize> PartialEq<[usize; N]> for Shape<D> {
+    /// Compares the dimensions of the shape with an array of lengths, so that a shape can be
+    /// checked with `*a.shape() == [2, 3]`.
+    fn eq(&self, other: &[usize; N]) -> bool {
+        self.dims() == other
+    }
+}
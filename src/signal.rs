@@ -0,0 +1,104 @@
+use std::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::{Array, Ix, Shape};
+
+/// How much of the full convolution `Array::convolve` returns, matching the modes of NumPy's
+/// `convolve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvMode {
+    /// Every point where the signals overlap, of length `n + m - 1`.
+    Full,
+    /// The centre of the full convolution, of length `max(n, m)`.
+    Same,
+    /// Only the points where the signals overlap completely, of length `max(n, m) - min(n, m) + 1`.
+    Valid,
+}
+
+impl<T: Zero + Mul<Output = T> + Copy> Array<T, Ix<1>> {
+    /// Computes the discrete linear convolution of `self` with `kernel`,
+    /// `out[k] = sum(self[i] * kernel[k - i])`, keeping the part selected by `mode`.
+    ///
+    /// Panics if either array is empty.
+    pub fn convolve(&self, kernel: &Array<T, Ix<1>>, mode: ConvMode) -> Array<T, Ix<1>> {
+        let (signal, kernel) = (self.as_slice(), kernel.as_slice());
+        assert!(
+            !signal.is_empty() && !kernel.is_empty(),
+            "convolve requires non-empty arrays"
+        );
+
+        let (n, m) = (signal.len(), kernel.len());
+        let mut full = vec![T::zero(); n + m - 1];
+        for (i, &a) in signal.iter().enumerate() {
+            for (out, &b) in full[i..i + m].iter_mut().zip(kernel) {
+                *out = *out + a * b;
+            }
+        }
+
+        let (len, start) = match mode {
+            ConvMode::Full => (n + m - 1, 0),
+            ConvMode::Same => (n.max(m), (n.min(m) - 1) / 2),
+            ConvMode::Valid => (n.max(m) - n.min(m) + 1, n.min(m) - 1),
+        };
+        let data = full[start..start + len].to_vec();
+        Array::from_parts(data, Shape::new(Ix::<1>::new([len])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConvMode;
+    use crate::{Array, Ix};
+
+    #[test]
+    fn convolve_f64_all_modes() {
+        let signal = arr![1.0, 2.0, 3.0];
+        let kernel = arr![0.0, 1.0, 0.5];
+
+        let full = signal.convolve(&kernel, ConvMode::Full);
+        assert_eq!(full.shape().dims(), &[5]);
+        assert_eq!(full.data(), &vec![0.0, 1.0, 2.5, 4.0, 1.5]);
+        assert_eq!(
+            signal.convolve(&kernel, ConvMode::Same).data(),
+            &vec![1.0, 2.5, 4.0]
+        );
+        assert_eq!(signal.convolve(&kernel, ConvMode::Valid).data(), &vec![2.5]);
+    }
+
+    #[test]
+    fn convolve_i64_uneven_lengths() {
+        let signal = arr![1, 2, 3, 4, 5];
+        let kernel = arr![1, -1];
+
+        assert_eq!(
+            signal.convolve(&kernel, ConvMode::Full).data(),
+            &vec![1, 1, 1, 1, 1, -5]
+        );
+        assert_eq!(
+            signal.convolve(&kernel, ConvMode::Same).data(),
+            &vec![1, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            signal.convolve(&kernel, ConvMode::Valid).data(),
+            &vec![1, 1, 1, 1]
+        );
+
+        // A kernel longer than the signal gives the same results with the roles swapped.
+        assert_eq!(
+            kernel.convolve(&signal, ConvMode::Same),
+            signal.convolve(&kernel, ConvMode::Same)
+        );
+        assert_eq!(
+            kernel.convolve(&signal, ConvMode::Valid).data(),
+            &vec![1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "convolve requires non-empty arrays")]
+    fn convolve_empty_panics() {
+        let empty: Array<i64, Ix<1>> = std::iter::empty().collect();
+        let _ = arr![1, 2].convolve(&empty, ConvMode::Full);
+    }
+}
@@ -0,0 +1,133 @@
+use std::any::type_name;
+use std::mem::{align_of, size_of, ManuallyDrop};
+use std::ops::Add;
+use std::slice;
+
+use crate::broadcast::BroadcastShape;
+use crate::{Array, Dimension, Shape};
+
+/// Number of elements added per step of the chunked kernel. Eight `f64`s fill an AVX-512 register
+/// and split evenly into narrower SSE/AVX/NEON registers.
+const LANES: usize = 8;
+
+/// Adds two equal-length slices in fixed-size chunks, which the compiler lowers to SIMD
+/// instructions, then adds the remainder one element at a time. Each output element is the same
+/// single IEEE addition as in the scalar loop, so the result is bit-for-bit identical.
+fn add_lanes<F: Add<Output = F> + Copy>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+    let mut out = Vec::with_capacity(lhs.len());
+    let mut lhs_chunks = lhs.chunks_exact(LANES);
+    let mut rhs_chunks = rhs.chunks_exact(LANES);
+
+    for (a, b) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+        let lanes: [F; LANES] = std::array::from_fn(|i| a[i] + b[i]);
+        out.extend_from_slice(&lanes);
+    }
+    out.extend(
+        lhs_chunks
+            .remainder()
+            .iter()
+            .zip(rhs_chunks.remainder())
+            .map(|(&a, &b)| a + b),
+    );
+    out
+}
+
+/// Runs `add_lanes` if `T` is the float type `F`, handing the result back as a `Vec<T>`. The type
+/// is matched by name, size and alignment rather than `TypeId`, so that `T` need not be
+/// `'static`.
+fn add_as<F, T>(lhs: &[T], rhs: &[T]) -> Option<Vec<T>>
+where
+    F: Add<Output = F> + Copy,
+{
+    if type_name::<T>() != type_name::<F>()
+        || size_of::<T>() != size_of::<F>()
+        || align_of::<T>() != align_of::<F>()
+    {
+        return None;
+    }
+
+    // SAFETY: `F` is a primitive float, and no other type shares its name, size and alignment,
+    // so `T` is `F` and both the slices and the returned buffer can be reinterpreted in place.
+    let (lhs, rhs) = unsafe {
+        (
+            slice::from_raw_parts(lhs.as_ptr().cast::<F>(), lhs.len()),
+            slice::from_raw_parts(rhs.as_ptr().cast::<F>(), rhs.len()),
+        )
+    };
+    let mut out = ManuallyDrop::new(add_lanes(lhs, rhs));
+    // SAFETY: as above; the allocation is handed over unchanged, with its length and capacity.
+    Some(unsafe { Vec::from_raw_parts(out.as_mut_ptr().cast::<T>(), out.len(), out.capacity()) })
+}
+
+/// Adds two `f32` or `f64` arrays of the same shape and memory order with the chunked SIMD
+/// kernel. Returns `None` for other element types or when the shapes or memory orders differ,
+/// leaving those cases to the strided scalar path.
+pub(crate) fn add_same_shape<T, D, E>(
+    lhs: &Array<T, D>,
+    rhs: &Array<T, E>,
+) -> Option<Array<T, D::Output>>
+where
+    D: BroadcastShape<E>,
+    E: Dimension,
+{
+    if lhs.shape().dims() != rhs.shape().dims() || lhs.order() != rhs.order() {
+        return None;
+    }
+
+    let data = add_as::<f64, T>(lhs.data(), rhs.data())
+        .or_else(|| add_as::<f32, T>(lhs.data(), rhs.data()))?;
+    let out_dim = lhs
+        .shape()
+        .raw_dim()
+        .broadcast_shape(rhs.shape().raw_dim())
+        .ok()?;
+    Some(Array::from_parts_in_order(
+        data,
+        Shape::new(out_dim),
+        lhs.order(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_same_shape;
+    use crate::{Array, Ix, Shape};
+
+    fn scalar_add<T: std::ops::Add<Output = T> + Copy>(lhs: &[T], rhs: &[T]) -> Vec<T> {
+        lhs.iter().zip(rhs).map(|(&a, &b)| a + b).collect()
+    }
+
+    #[test]
+    fn add_same_shape_f64_matches_scalar_for_ragged_lengths() {
+        for len in [0, 1, 7, 8, 9, 15, 17, 1003] {
+            let shape = Shape::new(Ix::<1>::new([len]));
+            let lhs = Array::from_shape_fn(shape.clone(), |ix| ix[0] as f64 * 0.1 - 3.0);
+            let rhs = Array::from_shape_fn(shape, |ix| 1.0 / (ix[0] as f64 + 0.3));
+
+            let simd = add_same_shape(&lhs, &rhs).unwrap();
+            let expected = scalar_add(lhs.data(), rhs.data());
+            let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+            assert_eq!(bits(simd.data()), bits(&expected), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn add_same_shape_f32_matches_scalar_2d() {
+        let shape = Shape::new(Ix::<2>::new([5, 13]));
+        let lhs = Array::from_shape_fn(shape.clone(), |ix| (ix[0] * 13 + ix[1]) as f32 * 0.7);
+        let rhs = Array::from_shape_fn(shape, |ix| -(ix[1] as f32) / 3.0);
+
+        let simd = add_same_shape(&lhs, &rhs).unwrap();
+        assert_eq!(simd.shape().dims(), &[5, 13]);
+        assert_eq!(simd.data(), &scalar_add(lhs.data(), rhs.data()));
+    }
+
+    #[test]
+    fn add_same_shape_falls_back() {
+        assert!(add_same_shape(&arr![1, 2, 3], &arr![4, 5, 6]).is_none());
+        assert!(add_same_shape(&arr![[1.0, 2.0]], &arr![[1.0], [2.0]]).is_none());
+
+        let sum = &arr![[0.5, 1.5]] + &arr![[1.0], [2.0]];
+        assert_eq!(sum.data(), &vec![1.5, 2.5, 2.5, 3.5]);
+    }
+}
@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+
+use crate::array::is_unordered;
+use crate::manipulation::assert_axis;
+use crate::{Array, Dimension, Ix, Shape};
+
+/// Compares two values like `partial_cmp`, ordering unordered values such as `NaN` after all
+/// others so that they end up at the back of a sorted array, as in NumPy.
+pub(crate) fn nan_last_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    match (is_unordered(a), is_unordered(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+    }
+}
+
+impl<T> Array<T, Ix<1>> {
+    /// Sorts the elements in ascending order in place. The sort is stable.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort();
+    }
+
+    /// Sorts the elements in place with a comparator function. The sort is stable.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Sorts partially ordered elements such as floats in ascending order in place, placing
+    /// `NaN` values at the end. The sort is stable.
+    pub fn sort_float(&mut self)
+    where
+        T: PartialOrd,
+    {
+        self.sort_by(nan_last_cmp);
+    }
+
+    /// Returns a new array with the elements sorted in ascending order, leaving this array
+    /// unchanged.
+    pub fn sorted(&self) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut sorted = self.flatten();
+        sorted.sort();
+        sorted
+    }
+
+    /// Returns the indices that would sort the elements in ascending order, keeping the original
+    /// order of equal elements. `NaN` values are ordered last, as in `sort_float`.
+    pub fn argsort(&self) -> Array<usize, Ix<1>>
+    where
+        T: PartialOrd,
+    {
+        let data = self.as_slice();
+        let mut indices: Vec<usize> = (0..data.len()).collect();
+        indices.sort_by(|&a, &b| nan_last_cmp(&data[a], &data[b]));
+        Array::from_parts(indices, self.shape().clone())
+    }
+}
+
+impl<T: Clone, D: Dimension> Array<T, D> {
+    /// Returns a sorted 1D array of the distinct elements across the whole array.
+    pub fn unique(&self) -> Array<T, Ix<1>>
+    where
+        T: Ord,
+    {
+        let mut data = self.data().clone();
+        data.sort();
+        data.dedup();
+        let shape = Shape::new(Ix::<1>::new([data.len()]));
+        Array::from_parts(data, shape)
+    }
+
+    /// Returns a sorted 1D array of the distinct elements across the whole array, for partially
+    /// ordered elements such as floats. All `NaN` values are collapsed into a single `NaN` at
+    /// the end, as with NumPy's `unique`.
+    pub fn unique_float(&self) -> Array<T, Ix<1>>
+    where
+        T: PartialOrd,
+    {
+        let mut data = self.data().clone();
+        data.sort_by(nan_last_cmp);
+        data.dedup_by(|a, b| nan_last_cmp(a, b) == Ordering::Equal);
+        let shape = Shape::new(Ix::<1>::new([data.len()]));
+        Array::from_parts(data, shape)
+    }
+}
+
+impl<T, D: Dimension> Array<T, D> {
+    /// Sorts each 1D lane along `axis` in ascending order in place, independently of the other
+    /// lanes. For a 2D array, axis 1 sorts every row and axis 0 sorts every column. The sort is
+    /// stable.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn sort_axis(&mut self, axis: usize)
+    where
+        T: Ord,
+    {
+        self.sort_axis_by(axis, T::cmp);
+    }
+
+    /// Sorts each 1D lane along `axis` like `sort_axis`, for partially ordered elements such as
+    /// floats, placing `NaN` values at the end of each lane.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn sort_axis_float(&mut self, axis: usize)
+    where
+        T: PartialOrd,
+    {
+        self.sort_axis_by(axis, nan_last_cmp);
+    }
+
+    /// Sorts each 1D lane along `axis` in place with a comparator function. The sort is stable.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn sort_axis_by<F: FnMut(&T, &T) -> Ordering>(&mut self, axis: usize, mut compare: F) {
+        assert_axis(axis, self.shape().dims().len());
+        let (outer, len, inner) = self.shape().axis_blocks(axis);
+        let offsets: Vec<usize> = (0..self.len()).map(|i| self.row_major_offset(i)).collect();
+        let data = self.as_mut_slice();
+
+        let mut order: Vec<usize> = Vec::with_capacity(len);
+        for o in 0..outer {
+            for k in 0..inner {
+                let at = |i: usize| offsets[o * len * inner + i * inner + k];
+                order.clear();
+                order.extend(0..len);
+                order.sort_by(|&a, &b| compare(&data[at(a)], &data[at(b)]));
+
+                // Lane position `i` must receive the element at `order[i]`. Following each cycle
+                // of the permutation moves the elements with swaps, without cloning them.
+                for start in 0..len {
+                    let mut i = start;
+                    while order[i] != start {
+                        let next = order[i];
+                        data.swap(at(i), at(next));
+                        order[i] = i;
+                        i = next;
+                    }
+                    order[i] = i;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Order;
+
+    #[test]
+    fn sort_i64_1d() {
+        let mut arr = arr![42, -17, 256, 3, 99, -8];
+        arr.sort();
+
+        assert_eq!(arr.data(), &vec![-17, -8, 3, 42, 99, 256]);
+        assert_eq!(arr.shape().dims(), &[6]);
+    }
+
+    #[test]
+    fn sort_by_descending() {
+        let mut arr = arr![3, 1, 2];
+        arr.sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(arr.data(), &vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_float_f64_with_nan() {
+        let mut arr = arr![2.5, f64::NAN, -1.0, 0.5];
+        arr.sort_float();
+
+        assert_eq!(arr.data()[..3], [-1.0, 0.5, 2.5]);
+        assert!(arr.data()[3].is_nan());
+    }
+
+    #[test]
+    fn sorted_leaves_original() {
+        let arr = arr![5, 3, 4];
+        let sorted = arr.sorted();
+
+        assert_eq!(sorted.data(), &vec![3, 4, 5]);
+        assert_eq!(arr.data(), &vec![5, 3, 4]);
+    }
+
+    #[test]
+    fn argsort_i64_1d() {
+        let arr = arr![30, 10, 20];
+        assert_eq!(arr.argsort().data(), &vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn argsort_stable_on_ties() {
+        let arr = arr![2, 1, 2, 1, 0];
+        assert_eq!(arr.argsort().data(), &vec![4, 1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn argsort_f64_reproduces_sorted_order() {
+        let arr = arr![4.67, -7.42, f64::NAN, 1.61, 0.98];
+        let order = arr.argsort();
+
+        let reordered: Vec<f64> = order.iter().map(|&i| arr.data()[i]).collect();
+        assert_eq!(reordered[..4], [-7.42, 0.98, 1.61, 4.67]);
+        assert!(reordered[4].is_nan());
+        assert_eq!(order.shape().dims(), &[5]);
+    }
+
+    #[test]
+    fn unique_i64_2d() {
+        let arr = arr![[3, 1], [1, 2]];
+        let unique = arr.unique();
+
+        assert_eq!(unique.data(), &vec![1, 2, 3]);
+        assert_eq!(unique.shape().dims(), &[3]);
+    }
+
+    #[test]
+    fn unique_float_f64_with_nan() {
+        let arr = arr![[2.5, f64::NAN, -0.5], [2.5, f64::NAN, 0.0]];
+        let unique = arr.unique_float();
+
+        assert_eq!(unique.shape().dims(), &[4]);
+        assert_eq!(unique.data()[..3], [-0.5, 0.0, 2.5]);
+        assert!(unique.data()[3].is_nan());
+    }
+
+    #[test]
+    fn sort_axis_i64_rows_keep_grouping() {
+        let mut arr = arr![[3, 1, 2], [9, 7, 8]];
+        arr.sort_axis(1);
+
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![1, 2, 3, 7, 8, 9]);
+    }
+
+    #[test]
+    fn sort_axis_i64_columns_and_3d() {
+        let mut arr = arr![[3, 10], [1, 30], [2, 20]];
+        arr.sort_axis(0);
+        assert_eq!(arr.data(), &vec![1, 10, 2, 20, 3, 30]);
+
+        let mut cube = arr![[[5, 4], [1, 2]], [[0, 9], [3, 6]]];
+        cube.sort_axis(1);
+        assert_eq!(cube.data(), &vec![1, 2, 5, 4, 0, 6, 3, 9]);
+    }
+
+    #[test]
+    fn sort_axis_float_and_by() {
+        let mut arr = arr![[2.5, f64::NAN, -1.0], [0.5, 0.25, 4.0]];
+        arr.sort_axis_float(1);
+        assert_eq!(arr.data()[..2], [-1.0, 2.5]);
+        assert!(arr.data()[2].is_nan());
+        assert_eq!(arr.data()[3..], [0.25, 0.5, 4.0]);
+
+        let mut arr = arr![[1, 3, 2], [6, 4, 5]];
+        arr.sort_axis_by(1, |a, b| b.cmp(a));
+        assert_eq!(arr.data(), &vec![3, 2, 1, 6, 5, 4]);
+    }
+
+    #[test]
+    fn sort_axis_col_major() {
+        let mut arr = arr![[3, 1, 2], [9, 7, 8]].to_order(Order::ColMajor);
+        arr.sort_axis(1);
+        assert_eq!(arr, arr![[1, 2, 3], [7, 8, 9]]);
+        assert_eq!(arr.order(), Order::ColMajor);
+
+        let mut arr = arr![[5, 1], [2, 6], [4, 3]].to_order(Order::ColMajor);
+        arr.sort_axis(0);
+        assert_eq!(arr, arr![[2, 1], [4, 3], [5, 6]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 is out of bounds for array with 2 dimensions")]
+    fn sort_axis_invalid_axis_panics() {
+        arr![[1, 2], [3, 4]].sort_axis(2);
+    }
+}
@@ -0,0 +1,484 @@
+use std::marker::PhantomData;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::shape::strided_offset;
+use crate::{Array, Dimension, InsertAxis, Ix, RemoveAxis, Shape};
+
+/// A single axis of a slice specification, as built by the `s!` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceInfoElem {
+    /// Keeps the axis, restricted to the half-open range `start..end`.
+    /// An `end` of `None` extends the range to the end of the axis.
+    Slice { start: usize, end: Option<usize> },
+
+    /// Selects a single position along the axis, removing the axis from the result.
+    Index(usize),
+}
+
+/// A slice specification taking an array of dimension `Din` to a view of dimension `Dout`.
+///
+/// This is usually built with the `s!` macro, which tracks both dimension types as each
+/// axis is added, so that slicing with a single index reduces the rank of the view.
+#[derive(Debug, Clone)]
+pub struct SliceInfo<Din, Dout> {
+    elems: Vec<SliceInfoElem>,
+    dims: PhantomData<(Din, Dout)>,
+}
+
+impl SliceInfo<Ix<0>, Ix<0>> {
+    /// Constructs an empty slice specification, to which axes are added with `push`.
+    pub fn empty() -> Self {
+        SliceInfo {
+            elems: Vec::new(),
+            dims: PhantomData,
+        }
+    }
+}
+
+impl<Din: InsertAxis, Dout: Dimension> SliceInfo<Din, Dout> {
+    /// Adds the next axis to the slice specification.
+    pub fn push<S, Dnext>(mut self, elem: S) -> SliceInfo<Din::Larger, Dnext>
+    where
+        S: SliceNextDim<Dout, Dnext>,
+        Dnext: Dimension,
+    {
+        self.elems.push(elem.into_elem());
+        SliceInfo {
+            elems: self.elems,
+            dims: PhantomData,
+        }
+    }
+}
+
+impl<Din, Dout> SliceInfo<Din, Dout> {
+    /// Returns the axes of the slice specification.
+    pub fn elems(&self) -> &[SliceInfoElem] {
+        &self.elems
+    }
+}
+
+/// Trait for the kinds of expressions accepted by `s!`, describing how each affects the
+/// dimension of the resulting view.
+///
+/// Ranges keep their axis, taking `D` to `D::Larger`, while a single `usize` index drops it.
+pub trait SliceNextDim<D, Next> {
+    /// Converts the expression into a slice specification axis.
+    fn into_elem(self) -> SliceInfoElem;
+}
+
+impl<D: Dimension> SliceNextDim<D, D> for usize {
+    fn into_elem(self) -> SliceInfoElem {
+        SliceInfoElem::Index(self)
+    }
+}
+
+/// Implements `SliceNextDim` for a range type, given how to extract its bounds.
+macro_rules! impl_slice_next_dim {
+    ($range:ty, |$r:ident| $start:expr, $end:expr) => {
+        impl<D: InsertAxis> SliceNextDim<D, D::Larger> for $range {
+            fn into_elem(self) -> SliceInfoElem {
+                let $r = self;
+                SliceInfoElem::Slice {
+                    start: $start,
+                    end: $end,
+                }
+            }
+        }
+    };
+}
+
+impl_slice_next_dim!(Range<usize>, |r| r.start, Some(r.end));
+impl_slice_next_dim!(RangeFrom<usize>, |r| r.start, None);
+impl_slice_next_dim!(RangeTo<usize>, |r| 0, Some(r.end));
+impl_slice_next_dim!(RangeFull, |_r| 0, None);
+// An inclusive end of `usize::MAX` has no exclusive counterpart, but it is out of bounds for any
+// axis anyway, so it saturates and is rejected by the bounds check in `slice`.
+impl_slice_next_dim!(
+    RangeInclusive<usize>,
+    |r| *r.start(),
+    Some(r.end().saturating_add(1))
+);
+impl_slice_next_dim!(
+    RangeToInclusive<usize>,
+    |r| 0,
+    Some(r.end.saturating_add(1))
+);
+
+/// A read-only view into the data of an array, with its own shape and strides.
+///
+/// Views are created with `Array::view` or `Array::slice` and borrow the underlying buffer
+/// rather than copying it, so the elements they see need not be contiguous.
+#[derive(Debug)]
+pub struct ArrayView<'a, T, D: Dimension> {
+    data: &'a [T],
+    shape: Shape<D>,
+    strides: D,
+}
+
+impl<T, D: Dimension> Clone for ArrayView<'_, T, D> {
+    fn clone(&self) -> Self {
+        ArrayView {
+            data: self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+}
+
+impl<'a, T, D: Dimension> ArrayView<'a, T, D> {
+    /// Constructs a view from a buffer starting at its first element, a shape and strides.
+    pub(crate) fn from_parts(data: &'a [T], shape: Shape<D>, strides: D) -> Self {
+        ArrayView {
+            data,
+            shape,
+            strides,
+        }
+    }
+
+    /// Returns a reference to the shape of the view.
+    pub fn shape(&self) -> &Shape<D> {
+        &self.shape
+    }
+
+    /// Returns the number of elements to step over in the underlying buffer to move by one
+    /// along each axis of the view.
+    pub fn strides(&self) -> &[usize] {
+        self.strides.dims()
+    }
+
+    /// Returns the elements of the view as a slice in row-major order, or `None` if they are
+    /// not contiguous in the underlying buffer.
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        let contiguous = self.strides.dims() == self.shape.strides().as_slice();
+        let size = self.shape.size();
+        (contiguous || size <= 1).then(|| &self.data[..size])
+    }
+
+    /// Returns a reference to the element at the given coordinate of the view, or `None` if
+    /// any component of the coordinate is out of bounds.
+    pub fn get(&self, index: D) -> Option<&'a T> {
+        let offset = strided_offset(self.shape.dims(), self.strides.dims(), index.dims())?;
+        self.data.get(offset)
+    }
+
+    /// Returns an iterator over the elements of the view in row-major order.
+    pub fn iter(&self) -> Iter<'a, T, D> {
+        let mut index = self.shape.raw_dim().clone();
+        index.dims_mut().fill(0);
+
+        Iter {
+            data: self.data,
+            dims: self.shape.raw_dim().clone(),
+            strides: self.strides.clone(),
+            index,
+            remaining: self.shape.size(),
+            contiguous: self.as_slice().is_some(),
+            position: 0,
+        }
+    }
+
+    /// Takes a sub-view described by a slice specification, usually built with `s!`.
+    ///
+    /// Panics if a range or index lies outside the bounds of its axis.
+    pub fn slice<E: Dimension>(&self, info: SliceInfo<D, E>) -> ArrayView<'a, T, E> {
+        let mut offset = 0;
+        let mut dims = Vec::with_capacity(info.elems.len());
+        let mut strides = Vec::with_capacity(info.elems.len());
+
+        let axes = self.shape.dims().iter().zip(self.strides.dims());
+        for (axis, (elem, (&len, &stride))) in info.elems.iter().zip(axes).enumerate() {
+            match *elem {
+                SliceInfoElem::Slice { start, end } => {
+                    let end = end.unwrap_or(len);
+                    if start > end || end > len {
+                        panic!(
+                            "Slice {}..{} is out of bounds for axis {} with length {}",
+                            start, end, axis, len
+                        );
+                    }
+                    offset += start * stride;
+                    dims.push(end - start);
+                    strides.push(stride);
+                }
+                SliceInfoElem::Index(index) => {
+                    if index >= len {
+                        panic!(
+                            "Index {} is out of bounds for axis {} with length {}",
+                            index, axis, len
+                        );
+                    }
+                    offset += index * stride;
+                }
+            }
+        }
+
+        let shape = Shape::new(E::from_dims(&dims).expect("slice info matches the view rank"));
+        let strides = E::from_dims(&strides).expect("slice info matches the view rank");
+        let data = if shape.size() == 0 {
+            &self.data[..0]
+        } else {
+            &self.data[offset..]
+        };
+
+        ArrayView::from_parts(data, shape, strides)
+    }
+
+    /// Returns an iterator over the sub-views along `axis`, one for each position along it,
+    /// each with that axis removed.
+    ///
+    /// Panics if `axis` is out of bounds for the view.
+    pub fn axis_iter(&self, axis: usize) -> impl Iterator<Item = ArrayView<'a, T, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        let ndim = self.shape.dims().len();
+        if axis >= ndim {
+            panic!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis, ndim
+            );
+        }
+
+        let data = self.data;
+        let len = self.shape.dims()[axis];
+        let stride = self.strides.dims()[axis];
+        let shape = Shape::new(self.shape.raw_dim().remove_axis(axis));
+        let strides = self.strides.remove_axis(axis);
+
+        (0..len).map(move |i| {
+            let data = if shape.size() == 0 {
+                &data[..0]
+            } else {
+                &data[i * stride..]
+            };
+            ArrayView::from_parts(data, shape.clone(), strides.clone())
+        })
+    }
+
+    /// Copies the elements of the view into a new, contiguous array.
+    pub fn to_owned(&self) -> Array<T, D>
+    where
+        T: Clone,
+    {
+        Array::from_parts(self.iter().cloned().collect(), self.shape.clone())
+    }
+}
+
+/// An iterator over the elements of a view in row-major order, created by `ArrayView::iter`.
+pub struct Iter<'a, T, D: Dimension> {
+    data: &'a [T],
+    dims: D,
+    strides: D,
+    index: D,
+    remaining: usize,
+    contiguous: bool,
+    position: usize,
+}
+
+impl<'a, T, D: Dimension> Iterator for Iter<'a, T, D> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.contiguous {
+            // Row-major data is visited in buffer order, so the coordinate need not be tracked.
+            self.remaining -= 1;
+            self.position += 1;
+            return Some(&self.data[self.position - 1]);
+        }
+
+        let offset: usize = self
+            .index
+            .dims()
+            .iter()
+            .zip(self.strides.dims())
+            .map(|(&i, &stride)| i * stride)
+            .sum();
+
+        // Advance the coordinate like an odometer, carrying into earlier axes.
+        for (i, &dim) in self.index.dims_mut().iter_mut().zip(self.dims.dims()).rev() {
+            *i += 1;
+            if *i < dim {
+                break;
+            }
+            *i = 0;
+        }
+        self.remaining -= 1;
+
+        Some(&self.data[offset])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, D: Dimension> ExactSizeIterator for Iter<'_, T, D> {}
+
+impl<T, D: Dimension> Array<T, D> {
+    /// Returns a view of the whole array.
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView::from_parts(
+            self.data(),
+            self.shape().clone(),
+            self.raw_strides().clone(),
+        )
+    }
+
+    /// Takes a view of part of the array, described by a slice specification built with `s!`.
+    /// The view borrows the array's data rather than copying it.
+    ///
+    /// Panics if a range or index lies outside the bounds of its axis.
+    pub fn slice<E: Dimension>(&self, info: SliceInfo<D, E>) -> ArrayView<'_, T, E> {
+        self.view().slice(info)
+    }
+
+    /// Returns an iterator over the sub-views along `axis`, such as the rows of a matrix for
+    /// axis 0, each with that axis removed.
+    ///
+    /// Panics if `axis` is out of bounds for the array.
+    pub fn axis_iter(&self, axis: usize) -> impl Iterator<Item = ArrayView<'_, T, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        self.view().axis_iter(axis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ix;
+
+    #[test]
+    fn slice_i64_2d_ranges() {
+        let arr = arr![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+        let view = arr.slice(s![0..2, 1..3]);
+
+        assert_eq!(view.shape().dims(), &[2, 2]);
+        assert_eq!(view.strides(), &[4, 1]);
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![2, 3, 6, 7]);
+        assert_eq!(view.get(Ix::<2>::new([1, 0])), Some(&6));
+        assert_eq!(view.get(Ix::<2>::new([2, 0])), None);
+    }
+
+    #[test]
+    fn slice_i64_2d_full_and_index() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        let column = arr.slice(s![.., 1]);
+        assert_eq!(column.shape().dims(), &[2]);
+        assert_eq!(column.iter().copied().collect::<Vec<_>>(), vec![2, 5]);
+
+        let row = arr.slice(s![1, ..]);
+        assert_eq!(row.to_owned().data(), &vec![4, 5, 6]);
+
+        let tail = arr.slice(s![.., 1..]);
+        assert_eq!(tail.to_owned().data(), &vec![2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn slice_f64_3d_index_reduces_rank() {
+        let arr = arr![
+            [[0.5, 1.5], [2.5, 3.5], [4.5, 5.5]],
+            [[6.5, 7.5], [8.5, 9.5], [10.5, 11.5]]
+        ];
+        let view = arr.slice(s![1, 0..=1, 1]);
+
+        assert_eq!(view.shape().dims(), &[2]);
+        assert_eq!(view.to_owned().data(), &vec![7.5, 9.5]);
+    }
+
+    #[test]
+    fn slice_of_view() {
+        let arr = arr![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+        let view = arr.slice(s![1.., ..]).slice(s![.., ..]);
+
+        assert_eq!(view.iter().count(), 8);
+        assert_eq!(view.slice(s![1, 2]).iter().next(), Some(&11));
+    }
+
+    #[test]
+    fn slice_empty_range() {
+        let arr = arr![[1, 2], [3, 4]];
+        let view = arr.slice(s![2..2, ..]);
+
+        assert_eq!(view.shape().dims(), &[0, 2]);
+        assert_eq!(view.iter().next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice 1..4 is out of bounds for axis 1 with length 3")]
+    fn slice_out_of_bounds_panics() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let _ = arr.slice(s![.., 1..4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds for axis 0 with length 3")]
+    fn slice_inclusive_to_usize_max_panics() {
+        let arr = arr![1, 2, 3];
+        let _ = arr.slice(s![..=usize::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds for axis 0 with length 3")]
+    fn slice_inclusive_range_to_usize_max_panics() {
+        let arr = arr![1, 2, 3];
+        let _ = arr.slice(s![1..=usize::MAX]);
+    }
+
+    #[test]
+    fn axis_iter_i64_2d_rows_and_columns() {
+        let arr = arr![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        let rows: Vec<Vec<i32>> = arr
+            .axis_iter(0)
+            .map(|row| row.iter().copied().collect())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]]
+        );
+
+        let columns: Vec<_> = arr.axis_iter(1).collect();
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns[1].shape().dims(), &[3]);
+        assert_eq!(columns[1].to_owned().data(), &vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn axis_iter_f64_3d() {
+        let arr = arr![[[0.5, 1.5], [2.5, 3.5]], [[4.5, 5.5], [6.5, 7.5]]];
+        let slices: Vec<_> = arr.axis_iter(2).collect();
+
+        assert_eq!(slices.len(), 2);
+        assert_eq!(slices[0].shape().dims(), &[2, 2]);
+        assert_eq!(slices[1].to_owned().data(), &vec![1.5, 3.5, 5.5, 7.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 is out of bounds for array with 2 dimensions")]
+    fn axis_iter_invalid_axis_panics() {
+        let arr = arr![[1, 2], [3, 4]];
+        let _ = arr.axis_iter(2);
+    }
+
+    #[test]
+    fn as_slice_contiguous_views() {
+        let arr = arr![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        assert_eq!(
+            arr.view().as_slice(),
+            Some(&[1, 2, 3, 4, 5, 6, 7, 8, 9][..])
+        );
+        assert_eq!(
+            arr.slice(s![1.., ..]).as_slice(),
+            Some(&[4, 5, 6, 7, 8, 9][..])
+        );
+        assert_eq!(arr.slice(s![1, 1..]).as_slice(), Some(&[5, 6][..]));
+        assert_eq!(arr.slice(s![.., 1]).as_slice(), None);
+        assert_eq!(arr.slice(s![0..2, 0..2]).as_slice(), None);
+    }
+}
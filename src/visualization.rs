@@ -1,5 +1,6 @@
 use crate::{errors::VisualizeError, Array, Dimension};
-use std::fmt::Display;
+use std::any::type_name;
+use std::fmt::{self, Debug, Display, Formatter};
 
 /// Trait for formatting values based on their type.
 pub trait FormatValue {
@@ -35,7 +36,7 @@ pub struct VisualizeBuilder<'a, T, D: Dimension> {
 
 impl<T: Display + FormatValue, D: Dimension> Array<T, D> {
     /// Starts the visualization process with default settings.
-    pub fn visualize(&self) -> VisualizeBuilder<T, D> {
+    pub fn visualize(&self) -> VisualizeBuilder<'_, T, D> {
         VisualizeBuilder {
             array: self,
             decimal_points: 1,
@@ -54,12 +55,12 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
     pub fn execute(&self) {
         let dims = self.array.shape().dims();
         let ndim = dims.len();
+        let data: Vec<&T> = self.array.iter().collect();
 
         if ndim == 1 {
             let rows = dims[0];
             print!("[");
-            for i in 0..rows {
-                let value = &self.array.data()[i];
+            for (i, value) in data.iter().enumerate() {
                 let value_str = value.format_value(self.decimal_points);
                 print!("{}", value_str.unwrap());
                 if i < rows - 1 {
@@ -73,20 +74,20 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
 
             let mut column_widths = vec![0; cols];
             for i in 0..rows {
-                for j in 0..cols {
-                    let value = &self.array.data()[i * cols + j];
+                for (j, column_width) in column_widths.iter_mut().enumerate() {
+                    let value = data[i * cols + j];
                     let width = value.format_value(self.decimal_points).unwrap().len();
-                    column_widths[j] = column_widths[j].max(width);
+                    *column_width = (*column_width).max(width);
                 }
             }
 
             println!("[");
             for i in 0..rows {
                 print!("   [");
-                for j in 0..cols {
-                    let value = &self.array.data()[i * cols + j];
+                for (j, column_width) in column_widths.iter().enumerate() {
+                    let value = data[i * cols + j];
                     let value_str = value.format_value(self.decimal_points);
-                    print!("{:width$}", value_str.unwrap(), width = column_widths[j]);
+                    print!("{:width$}", value_str.unwrap(), width = *column_width);
                     if j < cols - 1 {
                         print!(", ");
                     }
@@ -102,10 +103,10 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
             let mut column_widths = vec![0; cols];
             for i in 0..depth {
                 for j in 0..rows {
-                    for k in 0..cols {
-                        let value = &self.array.data()[(i * rows * cols) + (j * cols) + k];
+                    for (k, column_width) in column_widths.iter_mut().enumerate() {
+                        let value = data[(i * rows * cols) + (j * cols) + k];
                         let width = value.format_value(self.decimal_points).unwrap().len();
-                        column_widths[k] = column_widths[k].max(width);
+                        *column_width = (*column_width).max(width);
                     }
                 }
             }
@@ -115,10 +116,10 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
                 println!("   [");
                 for j in 0..rows {
                     print!("      [");
-                    for k in 0..cols {
-                        let value = &self.array.data()[(i * rows * cols) + (j * cols) + k];
+                    for (k, column_width) in column_widths.iter().enumerate() {
+                        let value = data[(i * rows * cols) + (j * cols) + k];
                         let value_str = value.format_value(self.decimal_points);
-                        print!("{:width$}", value_str.unwrap(), width = column_widths[k]);
+                        print!("{:width$}", value_str.unwrap(), width = *column_width);
                         if k < cols - 1 {
                             print!(", ");
                         }
@@ -134,3 +135,170 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
         }
     }
 }
+
+impl<T: Display, D: Dimension> Display for Array<T, D> {
+    /// Formats the array with nested brackets in the style of NumPy: one row per line for 2D
+    /// arrays and blocks separated by blank lines for higher dimensions. Values are right-aligned
+    /// to a common width per column, and a precision such as `{:.2}` is applied to every value.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self
+            .iter()
+            .map(|value| match f.precision() {
+                Some(precision) => format!("{:.*}", precision, value),
+                None => value.to_string(),
+            })
+            .collect();
+
+        let dims = self.shape().dims();
+        let cols = dims.last().copied().unwrap_or(1);
+        let mut widths = vec![0; cols];
+        for (i, value) in values.iter().enumerate() {
+            widths[i % cols] = widths[i % cols].max(value.len());
+        }
+
+        if dims.is_empty() {
+            return write!(f, "{}", values[0]);
+        }
+        write_nested(f, &values, dims, &widths, 0)
+    }
+}
+
+/// Writes the block of formatted `values` with shape `dims`, nested `depth` levels deep.
+fn write_nested(
+    f: &mut Formatter<'_>,
+    values: &[String],
+    dims: &[usize],
+    widths: &[usize],
+    depth: usize,
+) -> fmt::Result {
+    let rest = &dims[1..];
+    write!(f, "[")?;
+
+    if rest.is_empty() {
+        for (i, (value, &width)) in values.iter().zip(widths).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:>width$}", value, width = width)?;
+        }
+    } else {
+        let block: usize = rest.iter().product();
+        for i in 0..dims[0] {
+            if i > 0 {
+                // Each level of nesting below this one adds a line break between blocks.
+                write!(f, ",{}{}", "\n".repeat(rest.len()), " ".repeat(depth + 1))?;
+            }
+            let values = &values[i * block..(i + 1) * block];
+            write_nested(f, values, rest, widths, depth + 1)?;
+        }
+    }
+
+    write!(f, "]")
+}
+
+impl<T: Debug, D: Dimension> Debug for Array<T, D> {
+    /// Formats the array as its shape, element type and data, with the data nested according
+    /// to the shape rather than shown as the flat backing vector.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Array")
+            .field("shape", &self.shape().dims())
+            .field("dtype", &format_args!("{}", type_name::<T>()))
+            .field(
+                "data",
+                &Nested {
+                    values: &self.iter().collect::<Vec<_>>(),
+                    dims: self.shape().dims(),
+                },
+            )
+            .finish()
+    }
+}
+
+/// A block of row-major values whose `Debug` output nests them as lists according to `dims`.
+struct Nested<'a, T> {
+    values: &'a [T],
+    dims: &'a [usize],
+}
+
+impl<T: Debug> Debug for Nested<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.dims {
+            [] => self.values[0].fmt(f),
+            [_] => f.debug_list().entries(self.values).finish(),
+            [len, rest @ ..] => {
+                let block: usize = rest.iter().product();
+                f.debug_list()
+                    .entries((0..*len).map(|i| Nested {
+                        values: &self.values[i * block..(i + 1) * block],
+                        dims: rest,
+                    }))
+                    .finish()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, Ix, Shape};
+
+    #[test]
+    fn display_i64_1d() {
+        assert_eq!(format!("{}", arr![1, 2, 3]), "[1, 2, 3]");
+        assert_eq!(format!("{}", arr![0.5, -1.25]), "[0.5, -1.25]");
+    }
+
+    #[test]
+    fn display_i64_2d_aligned() {
+        let arr = arr![[1, -20, 3], [400, 5, 6]];
+        assert_eq!(format!("{}", arr), "[[  1, -20, 3],\n [400,   5, 6]]");
+    }
+
+    #[test]
+    fn display_f64_2d_precision() {
+        let arr = arr![[1.0, 2.5], [-7.41892, 10.0]];
+        assert_eq!(format!("{:.2}", arr), "[[ 1.00,  2.50],\n [-7.42, 10.00]]");
+    }
+
+    #[test]
+    fn display_i64_3d_blocks() {
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 80]]];
+        assert_eq!(
+            format!("{}", arr),
+            "[[[1,  2],\n  [3,  4]],\n\n [[5,  6],\n  [7, 80]]]"
+        );
+    }
+
+    #[test]
+    fn display_empty() {
+        let empty = Array::<f64, Ix<2>>::eye(0);
+        assert_eq!(format!("{}", empty), "[]");
+    }
+
+    #[test]
+    fn debug_i64_2d_nested() {
+        let arr = arr![[1, 2], [3, 4], [5, 6]];
+        assert_eq!(
+            format!("{:?}", arr),
+            "Array { shape: [3, 2], dtype: i32, data: [[1, 2], [3, 4], [5, 6]] }"
+        );
+    }
+
+    #[test]
+    fn debug_f64_3d_nested() {
+        let arr = arr![[[0.5], [1.5]], [[2.5], [3.5]]];
+        assert_eq!(
+            format!("{:?}", arr),
+            "Array { shape: [2, 2, 1], dtype: f64, data: [[[0.5], [1.5]], [[2.5], [3.5]]] }"
+        );
+    }
+
+    #[test]
+    fn debug_empty() {
+        let empty = Array::<f64, Ix<1>>::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert_eq!(
+            format!("{:?}", empty),
+            "Array { shape: [0], dtype: f64, data: [] }"
+        );
+    }
+}